@@ -10,8 +10,10 @@ fn create_default_motion_law() -> MotionLaw {
 
 // Helper function to create a motion law with high RPM
 fn create_high_rpm_motion_law() -> MotionLaw {
-    let mut params = MotionParameters::default();
-    params.rpm = 6000.0;
+    let params = MotionParameters {
+        rpm: 6000.0,
+        ..MotionParameters::default()
+    };
     MotionLaw::new(params).unwrap()
 }
 
@@ -93,6 +95,51 @@ fn bench_single_threaded(c: &mut Criterion) {
     }
     group.finish();
     
+    // Benchmark the derivative channels over a full sweep; the angular-rate powers are
+    // cached in MotionLaw::new, compare runs with `--save-baseline`/`--baseline`
+    let mut group = c.benchmark_group("cached_omega_constants");
+    group.measurement_time(Duration::from_secs(10));
+    
+    let sweep = create_angle_vector(3600);
+    group.bench_function("velocity_acceleration_jerk_sweep", |b| {
+        b.iter(|| {
+            let mut sum = 0.0;
+            for &angle in sweep.iter() {
+                sum += motion.velocity(black_box(angle));
+                sum += motion.acceleration(black_box(angle));
+                sum += motion.jerk(black_box(angle));
+            }
+            sum
+        })
+    });
+    group.bench_function("evaluate_sweep", |b| {
+        b.iter(|| {
+            let mut sum = 0.0;
+            for &angle in sweep.iter() {
+                let sample = motion.evaluate(black_box(angle));
+                sum += sample.velocity + sample.acceleration + sample.jerk;
+            }
+            sum
+        })
+    });
+    group.finish();
+    
+    // Benchmark evaluation on a reused, precomputed angle grid
+    let mut group = c.benchmark_group("precomputed_grid");
+    group.measurement_time(Duration::from_secs(10));
+    
+    let grid = motion.precompute_grid(&sweep);
+    group.bench_function("evaluate_per_angle", |b| {
+        b.iter(|| sweep.iter().map(|&angle| motion.evaluate(black_box(angle))).collect::<Vec<_>>())
+    });
+    group.bench_function("evaluate_grid", |b| {
+        b.iter(|| motion.evaluate_grid(black_box(&grid)))
+    });
+    group.bench_function("evaluate_grid_high_rpm", |b| {
+        b.iter(|| high_rpm_motion.evaluate_grid(black_box(&grid)))
+    });
+    group.finish();
+    
     // Benchmark boundary condition calculation
     let mut group = c.benchmark_group("boundary_condition_at_time");
     group.measurement_time(Duration::from_secs(10));
@@ -100,11 +147,11 @@ fn bench_single_threaded(c: &mut Criterion) {
     let time_steps = create_time_vector(8);
     for &time in time_steps.iter() {
         group.bench_with_input(BenchmarkId::new("default_rpm", time), &time, |b, &time| {
-            b.iter(|| motion.boundary_condition_at_time(black_box(*time)))
+            b.iter(|| motion.boundary_condition_at_time(black_box(time)))
         });
         
         group.bench_with_input(BenchmarkId::new("high_rpm", time), &time, |b, &time| {
-            b.iter(|| high_rpm_motion.boundary_condition_at_time(black_box(*time)))
+            b.iter(|| high_rpm_motion.boundary_condition_at_time(black_box(time)))
         });
     }
     group.finish();
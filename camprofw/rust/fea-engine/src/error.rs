@@ -42,6 +42,10 @@ pub enum FEAError {
     /// JNI error
     #[error("JNI error: {0}")]
     JNI(String),
+
+    /// Malformed key/value string array passed over JNI (odd length or null elements)
+    #[error("JNI error: invalid parameter array: {0}")]
+    JNIParameterArray(String),
     
     /// Unknown error
     #[error("Unknown error: {0}")]
@@ -51,6 +55,53 @@ pub enum FEAError {
 /// Result type for the FEA engine
 pub type FEAResult<T> = Result<T, FEAError>;
 
+impl FEAError {
+    /// Name of the error variant, e.g. `"Deserialization"`
+    pub fn variant_name(&self) -> &'static str {
+        match self {
+            FEAError::ParameterValidation(_) => "ParameterValidation",
+            FEAError::Calculation(_) => "Calculation",
+            FEAError::IO(_) => "IO",
+            FEAError::Serialization(_) => "Serialization",
+            FEAError::Deserialization(_) => "Deserialization",
+            FEAError::BoundaryCondition(_) => "BoundaryCondition",
+            FEAError::Simulation(_) => "Simulation",
+            FEAError::JNI(_) => "JNI",
+            FEAError::JNIParameterArray(_) => "JNIParameterArray",
+            FEAError::Unknown(_) => "Unknown",
+        }
+    }
+}
+
+thread_local! {
+    static LAST_ERROR: std::cell::RefCell<Option<ErrorReport>> = const { std::cell::RefCell::new(None) };
+}
+
+/// Record an error as the current thread's last error
+///
+/// The stored report's `error_type` is the variant name of `err`, so callers
+/// across the JNI boundary can branch on the kind of failure.
+pub fn record_error(err: &FEAError, file: &str, line: u32, function: &str) {
+    let report = ErrorReport::new(
+        err.to_string(),
+        err.variant_name().to_string(),
+        file.to_string(),
+        line,
+        function.to_string(),
+    );
+    LAST_ERROR.with(|slot| *slot.borrow_mut() = Some(report));
+}
+
+/// The current thread's last recorded error as JSON, if any
+pub fn last_error_json() -> Option<String> {
+    LAST_ERROR.with(|slot| slot.borrow().as_ref().map(ErrorReport::to_json))
+}
+
+/// Forget the current thread's last recorded error
+pub fn clear_last_error() {
+    LAST_ERROR.with(|slot| *slot.borrow_mut() = None);
+}
+
 /// Convert a string error to a FEAError::ParameterValidation
 pub fn parameter_validation_error<S: Into<String>>(msg: S) -> FEAError {
     FEAError::ParameterValidation(msg.into())
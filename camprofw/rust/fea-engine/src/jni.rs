@@ -4,8 +4,8 @@
 //! It allows Kotlin code to call into the Rust implementation.
 
 use jni::JNIEnv;
-use jni::objects::{JClass, JString, JObject, JObjectArray, JValue};
-use jni::sys::{jlong, jdouble, jint, jobjectArray, jstring, jboolean};
+use jni::objects::{JClass, JDoubleArray, JString, JObject, JObjectArray, JValue};
+use jni::sys::{jlong, jdouble, jdoubleArray, jint, jobjectArray, jstring, jboolean};
 use std::collections::HashMap;
 use std::sync::{Arc, Mutex};
 use std::fs::File;
@@ -13,9 +13,9 @@ use std::io::Write;
 use std::path::Path;
 use serde_json;
 
-use crate::motion_law::{MotionLaw, MotionParameters, KinematicAnalysis};
-use crate::error::FEAResult;
-use crate::litvin::{self, LitvinParameters, LitvinTables, PitchCurves, PlanetState};
+use crate::motion_law::{AccelSignConvention, ModifiedSineVariant, MotionLaw, MotionParameters, KinematicAnalysis, DEFAULT_MAX_ANALYSIS_POINTS, KINEMATIC_COLUMNS};
+use crate::error::{FEAError, FEAResult};
+use crate::litvin::{self, Diagnostics, InterpOrder, LitvinParameters, LitvinTables, MeshType, PitchCurves, PlanetState};
 
 // Global storage for motion law instances
 lazy_static! {
@@ -25,6 +25,8 @@ lazy_static! {
     static ref LITVIN_TABLES: Mutex<HashMap<jlong, Arc<LitvinTables>>> = Mutex::new(HashMap::new());
     // Temp directories per Litvin ID (for JSON file cleanup)
     static ref LITVIN_TMPDIRS: Mutex<HashMap<jlong, std::path::PathBuf>> = Mutex::new(HashMap::new());
+    // Current playback alpha (deg) per Litvin ID for the stateful animation calls
+    static ref LITVIN_ALPHA: Mutex<HashMap<jlong, f64>> = Mutex::new(HashMap::new());
 }
 
 /// Get the next available ID for a motion law
@@ -35,32 +37,71 @@ fn get_next_id() -> jlong {
     current
 }
 
+/// Record `err` as the thread's last error, located at the caller, and throw it as a Java exception
+#[track_caller]
+fn throw_fea_error(env: &mut JNIEnv, context: &str, err: &FEAError) {
+    let caller = std::panic::Location::caller();
+    crate::error::record_error(err, caller.file(), caller.line(), context);
+    let _ = env.throw(format!("{}: {}", context, err));
+}
+
 /// Convert a Java string array to a Rust HashMap
 fn string_array_to_map(env: &mut JNIEnv, array: jobjectArray) -> FEAResult<HashMap<String, String>> {
     let array_ref = unsafe { JObjectArray::from_raw(array) };
     let length = env.get_array_length(&array_ref)?;
-    if length % 2 != 0 {
-        return Err(crate::error::FEAError::JNI("String array length must be even".to_string()));
+    let mut items = Vec::with_capacity(length.max(0) as usize);
+    for i in 0..length {
+        let obj = env.get_object_array_element(&array_ref, i)?;
+        if obj.is_null() {
+            items.push(None);
+        } else {
+            items.push(Some(env.get_string(&JString::from(obj))?.into()));
+        }
     }
-    
+    pairs_to_map(&items)
+}
+
+/// Number of leading keys quoted in parameter array errors
+const PARAMETER_ARRAY_KEYS_SHOWN: usize = 3;
+
+/// Pair up a flattened `[key0, value0, key1, value1, ...]` array; `None` marks a Java null.
+fn pairs_to_map(items: &[Option<String>]) -> FEAResult<HashMap<String, String>> {
+    if !items.len().is_multiple_of(2) {
+        let keys: Vec<String> = items
+            .iter()
+            .step_by(2)
+            .take(PARAMETER_ARRAY_KEYS_SHOWN)
+            .map(|k| k.as_deref().map_or("null".to_string(), |k| format!("{:?}", k)))
+            .collect();
+        return Err(FEAError::JNIParameterArray(format!(
+            "length {} is odd, expected key/value pairs (first keys: [{}])",
+            items.len(),
+            keys.join(", ")
+        )));
+    }
+
     let mut map = HashMap::new();
-    
-    for i in 0..(length / 2) {
-        let key_obj = env.get_object_array_element(&array_ref, i * 2)?;
-        let value_obj = env.get_object_array_element(&array_ref, i * 2 + 1)?;
-        
-        let key_string = JString::from(key_obj);
-        let value_string = JString::from(value_obj);
-        
-        let key: String = env.get_string(&key_string)?.into();
-        let value: String = env.get_string(&value_string)?.into();
-        
-        map.insert(key, value);
+    for (pair, kv) in items.chunks_exact(2).enumerate() {
+        let key = kv[0].as_ref().ok_or_else(|| {
+            FEAError::JNIParameterArray(format!("null key at index {}", pair * 2))
+        })?;
+        let value = kv[1].as_ref().ok_or_else(|| {
+            FEAError::JNIParameterArray(format!("null value for key {:?} at index {}", key, pair * 2 + 1))
+        })?;
+        map.insert(key.clone(), value.clone());
     }
-    
     Ok(map)
 }
 
+/// Convert a Java double array to a Rust Vec
+fn double_array_to_vec(env: &mut JNIEnv, array: jdoubleArray) -> FEAResult<Vec<f64>> {
+    let array_ref = unsafe { JDoubleArray::from_raw(array) };
+    let length = env.get_array_length(&array_ref)?;
+    let mut values = vec![0.0; length as usize];
+    env.get_double_array_region(&array_ref, 0, &mut values)?;
+    Ok(values)
+}
+
 /// Convert a HashMap to a MotionParameters struct
 fn map_to_motion_parameters(map: HashMap<String, String>) -> FEAResult<MotionParameters> {
     let base_circle_radius = map.get("base_circle_radius")
@@ -103,6 +144,20 @@ fn map_to_motion_parameters(map: HashMap<String, String>) -> FEAResult<MotionPar
         .and_then(|s| s.parse::<f64>().ok())
         .unwrap_or(3000.0);
     
+    let accel_sign_convention = match map.get("accel_sign_convention").map(|s| s.to_lowercase()) {
+        Some(s) if s == "legacy" => AccelSignConvention::Legacy,
+        _ => AccelSignConvention::Physical,
+    };
+    
+    let modified_sine_variant = match map.get("modified_sine_variant").map(|s| s.to_lowercase()) {
+        Some(s) if s == "split_region" || s == "splitregion" => ModifiedSineVariant::SplitRegion,
+        _ => ModifiedSineVariant::Standard,
+    };
+    
+    let phase_boundary_epsilon = map.get("phase_boundary_epsilon")
+        .and_then(|s| s.parse::<f64>().ok())
+        .unwrap_or(crate::motion_law::DEFAULT_PHASE_BOUNDARY_EPSILON);
+    
     Ok(MotionParameters {
         base_circle_radius,
         max_lift,
@@ -114,6 +169,9 @@ fn map_to_motion_parameters(map: HashMap<String, String>) -> FEAResult<MotionPar
         acceleration_limit,
         velocity_limit,
         rpm,
+        accel_sign_convention,
+        modified_sine_variant,
+        phase_boundary_epsilon,
     })
 }
 
@@ -143,7 +201,7 @@ pub extern "system" fn Java_com_campro_v5_animation_MotionLawEngine_createMotion
             id
         }
         Err(e) => {
-            let _ = env.throw(format!("Failed to create motion law: {}", e));
+            throw_fea_error(&mut env, "Failed to create motion law", &e);
             0
         }
     }
@@ -166,7 +224,7 @@ pub extern "system" fn Java_com_campro_v5_animation_MotionLawEngine_updateMotion
             motion_laws.insert(motion_law_id, Arc::new(motion_law));
         }
         Err(e) => {
-            let _ = env.throw(format!("Failed to update motion law parameters: {}", e));
+            throw_fea_error(&mut env, "Failed to update motion law parameters", &e);
         }
     }
 }
@@ -182,7 +240,7 @@ pub extern "system" fn Java_com_campro_v5_animation_MotionLawEngine_getDisplacem
     match get_motion_law(motion_law_id) {
         Ok(motion_law) => motion_law.displacement(angle),
         Err(e) => {
-            let _ = env.throw(format!("Failed to get displacement: {}", e));
+            throw_fea_error(&mut env, "Failed to get displacement", &e);
             0.0
         }
     }
@@ -199,7 +257,7 @@ pub extern "system" fn Java_com_campro_v5_animation_MotionLawEngine_getVelocityN
     match get_motion_law(motion_law_id) {
         Ok(motion_law) => motion_law.velocity(angle),
         Err(e) => {
-            let _ = env.throw(format!("Failed to get velocity: {}", e));
+            throw_fea_error(&mut env, "Failed to get velocity", &e);
             0.0
         }
     }
@@ -216,7 +274,7 @@ pub extern "system" fn Java_com_campro_v5_animation_MotionLawEngine_getAccelerat
     match get_motion_law(motion_law_id) {
         Ok(motion_law) => motion_law.acceleration(angle),
         Err(e) => {
-            let _ = env.throw(format!("Failed to get acceleration: {}", e));
+            throw_fea_error(&mut env, "Failed to get acceleration", &e);
             0.0
         }
     }
@@ -233,7 +291,7 @@ pub extern "system" fn Java_com_campro_v5_animation_MotionLawEngine_getJerkNativ
     match get_motion_law(motion_law_id) {
         Ok(motion_law) => motion_law.jerk(angle),
         Err(e) => {
-            let _ = env.throw(format!("Failed to get jerk: {}", e));
+            throw_fea_error(&mut env, "Failed to get jerk", &e);
             0.0
         }
     }
@@ -251,14 +309,22 @@ pub extern "system" fn Java_com_campro_v5_animation_MotionLawEngine_analyzeKinem
     let results_file_path: String = match env.get_string(&results_file_path) {
         Ok(s) => s.into(),
         Err(e) => {
-            let _ = env.throw(format!("Failed to get results file path: {}", e));
+            throw_fea_error(&mut env, "Failed to get results file path", &FEAError::JNI(e.to_string()));
             return;
         }
     };
     
     match get_motion_law(motion_law_id) {
         Ok(motion_law) => {
-            let analysis = motion_law.analyze_kinematics(num_points as usize);
+            // Negative counts from the host are rejected rather than wrapped to huge sizes
+            let requested = usize::try_from(num_points).unwrap_or(0);
+            let analysis = match motion_law.analyze_kinematics_checked(requested, DEFAULT_MAX_ANALYSIS_POINTS) {
+                Ok(analysis) => analysis,
+                Err(e) => {
+                    throw_fea_error(&mut env, "Failed to analyze kinematics", &e);
+                    return;
+                }
+            };
             
             // Serialize the analysis to JSON
             match serde_json::to_string_pretty(&analysis) {
@@ -267,21 +333,69 @@ pub extern "system" fn Java_com_campro_v5_animation_MotionLawEngine_analyzeKinem
                     match File::create(Path::new(&results_file_path)) {
                         Ok(mut file) => {
                             if let Err(e) = file.write_all(json.as_bytes()) {
-                                let _ = env.throw(format!("Failed to write to results file: {}", e));
+                                throw_fea_error(&mut env, "Failed to write to results file", &FEAError::from(e));
                             }
                         }
                         Err(e) => {
-                            let _ = env.throw(format!("Failed to create results file: {}", e));
+                            throw_fea_error(&mut env, "Failed to create results file", &FEAError::from(e));
                         }
                     }
                 }
                 Err(e) => {
-                    let _ = env.throw(format!("Failed to serialize analysis: {}", e));
+                    throw_fea_error(&mut env, "Failed to serialize analysis", &FEAError::from(e));
                 }
             }
         }
         Err(e) => {
-            let _ = env.throw(format!("Failed to analyze kinematics: {}", e));
+            throw_fea_error(&mut env, "Failed to analyze kinematics", &e);
+        }
+    }
+}
+
+/// Flatten the analysis columns into one array: a header `[column_count, row_count,
+/// offset_0, ..., offset_{column_count-1}]` followed by the columns in [`KINEMATIC_COLUMNS`]
+/// order, each offset being the index of that column's first value.
+fn flatten_columns(analysis: &KinematicAnalysis) -> Vec<f64> {
+    let columns = analysis.as_columns();
+    let rows = analysis.theta.len();
+    let header_len = 2 + KINEMATIC_COLUMNS.len();
+    let mut flat = Vec::with_capacity(header_len + rows * KINEMATIC_COLUMNS.len());
+    flat.push(KINEMATIC_COLUMNS.len() as f64);
+    flat.push(rows as f64);
+    flat.extend((0..KINEMATIC_COLUMNS.len()).map(|c| (header_len + c * rows) as f64));
+    for name in KINEMATIC_COLUMNS {
+        flat.extend_from_slice(columns[name]);
+    }
+    flat
+}
+
+/// Analyze kinematics and return the columns as one flattened double array (see `flatten_columns`)
+#[no_mangle]
+pub extern "system" fn Java_com_campro_v5_animation_MotionLawEngine_analyzeKinematicsColumnsNative(
+    mut env: JNIEnv,
+    _class: JClass,
+    motion_law_id: jlong,
+    num_points: jint,
+) -> jdoubleArray {
+    let requested = usize::try_from(num_points).unwrap_or(0);
+    let flat = match get_motion_law(motion_law_id)
+        .and_then(|motion_law| motion_law.analyze_kinematics_checked(requested, DEFAULT_MAX_ANALYSIS_POINTS))
+    {
+        Ok(analysis) => flatten_columns(&analysis),
+        Err(e) => {
+            throw_fea_error(&mut env, "Failed to analyze kinematics", &e);
+            return std::ptr::null_mut();
+        }
+    };
+    let result = env.new_double_array(flat.len() as i32).and_then(|array| {
+        env.set_double_array_region(&array, 0, &flat)?;
+        Ok(array)
+    });
+    match result {
+        Ok(array) => array.into_raw(),
+        Err(e) => {
+            throw_fea_error(&mut env, "Failed to create columns array", &FEAError::from(e));
+            std::ptr::null_mut()
         }
     }
 }
@@ -295,7 +409,7 @@ pub extern "system" fn Java_com_campro_v5_animation_MotionLawEngine_disposeMotio
 ) {
     let mut motion_laws = MOTION_LAWS.lock().unwrap();
     if motion_laws.remove(&motion_law_id).is_none() {
-        let _ = env.throw(format!("Motion law with ID {} not found", motion_law_id));
+        throw_fea_error(&mut env, "Failed to dispose motion law", &FEAError::JNI(format!("Motion law with ID {} not found", motion_law_id)));
     }
 }
 
@@ -309,6 +423,19 @@ pub extern "system" fn Java_com_campro_v5_animation_MotionLawEngine_00024Compani
     42
 }
 
+/// Get the calling thread's last recorded error as structured JSON
+///
+/// The `error_type` field carries the `FEAError` variant name so Kotlin can
+/// branch on the kind of failure; returns a placeholder object when no error
+/// has been recorded.
+#[no_mangle]
+pub extern "system" fn Java_com_campro_v5_animation_MotionLawEngine_getLastErrorJsonNative(
+    env: JNIEnv,
+    _class: JClass,
+) -> jstring {
+    env.new_string(crate::get_last_error()).map(|s| s.into_raw()).unwrap_or(std::ptr::null_mut())
+}
+
 /// Test function to verify that the FEA library is working correctly
 /// The function should return 42 as expected by the Kotlin code
 #[no_mangle]
@@ -333,14 +460,22 @@ fn map_to_litvin_parameters(mut map: HashMap<String, String>) -> FEAResult<Litvi
     fn get_i(map: &mut HashMap<String, String>, k: &str, d: i32) -> i32 {
         map.remove(k).and_then(|s| s.parse::<i32>().ok()).unwrap_or(d)
     }
+    fn get_profile(map: &mut HashMap<String, String>, k: &str) -> Option<litvin::RampProfile> {
+        match map.remove(k)?.to_lowercase().as_str() {
+            "cycloidal" => Some(litvin::RampProfile::Cycloidal),
+            "s5" => Some(litvin::RampProfile::S5),
+            "s7" => Some(litvin::RampProfile::S7),
+            _ => None,
+        }
+    }
     let def = LitvinParameters::default();
 
     // take and normalize ramp_profile first via remove (no immutable borrow)
-    let ramp_profile = match map.remove("ramp_profile").map(|s| s.to_lowercase()) {
-        Some(s) if s == "cycloidal" => litvin::RampProfile::Cycloidal,
-        Some(s) if s == "s7" => litvin::RampProfile::S7,
-        _ => litvin::RampProfile::S5,
-    };
+    let ramp_profile = get_profile(&mut map, "ramp_profile").unwrap_or(litvin::RampProfile::S5);
+    let ramp_after_tdc_profile = get_profile(&mut map, "ramp_after_tdc_profile");
+    let ramp_before_bdc_profile = get_profile(&mut map, "ramp_before_bdc_profile");
+    let ramp_after_bdc_profile = get_profile(&mut map, "ramp_after_bdc_profile");
+    let ramp_before_tdc_profile = get_profile(&mut map, "ramp_before_tdc_profile");
 
     let up_fraction = get_f(&mut map, "up_fraction", def.up_fraction);
     let dwell_tdc_deg = get_f(&mut map, "dwell_tdc_deg", def.dwell_tdc_deg);
@@ -366,6 +501,57 @@ fn map_to_litvin_parameters(mut map: HashMap<String, String>) -> FEAResult<Litvi
 
     let arc_residual_tol_mm = get_f(&mut map, "arc_residual_tol_mm", def.arc_residual_tol_mm);
     let max_iter = get_i(&mut map, "max_iter", def.max_iter);
+    let min_module = get_f(&mut map, "min_module", def.min_module);
+    let export_cartesian_curves = map.remove("export_cartesian_curves")
+        .and_then(|s| s.parse::<bool>().ok())
+        .unwrap_or(def.export_cartesian_curves);
+    let compute_journal_geometry = map.remove("compute_journal_geometry")
+        .and_then(|s| s.parse::<bool>().ok())
+        .unwrap_or(def.compute_journal_geometry);
+    let capture_iterates = map.remove("capture_iterates")
+        .and_then(|s| s.parse::<bool>().ok())
+        .unwrap_or(def.capture_iterates);
+    let reverse_rotation = map.remove("reverse_rotation")
+        .and_then(|s| s.parse::<bool>().ok())
+        .unwrap_or(def.reverse_rotation);
+    let min_clearance_target = get_f(&mut map, "min_clearance_target", def.min_clearance_target);
+    let max_sliding_velocity = map.remove("max_sliding_velocity")
+        .and_then(|s| s.parse::<f64>().ok())
+        .or(def.max_sliding_velocity);
+    let mean_piston_speed_mps = map.remove("mean_piston_speed_mps")
+        .and_then(|s| s.parse::<f64>().ok())
+        .or(def.mean_piston_speed_mps);
+    let nvh_amp_limit = map.remove("nvh_amp_limit")
+        .and_then(|s| s.parse::<f64>().ok())
+        .or(def.nvh_amp_limit);
+    let build_time_budget_ms = map.remove("build_time_budget_ms")
+        .and_then(|s| s.parse::<f64>().ok())
+        .or(def.build_time_budget_ms);
+    let accel_limit = map.remove("accel_limit")
+        .and_then(|s| s.parse::<f64>().ok())
+        .or(def.accel_limit);
+    let jerk_limit = map.remove("jerk_limit")
+        .and_then(|s| s.parse::<f64>().ok())
+        .or(def.jerk_limit);
+    let nvh_cycles = map.remove("nvh_cycles")
+        .and_then(|s| s.parse::<u32>().ok())
+        .unwrap_or(def.nvh_cycles);
+    let phi_refine_steps = map.remove("phi_refine_steps")
+        .and_then(|s| s.parse::<u32>().ok())
+        .unwrap_or(def.phi_refine_steps);
+    let output_decimals = map.remove("output_decimals")
+        .and_then(|s| s.parse::<u32>().ok())
+        .or(def.output_decimals);
+    let interp_order = match map.remove("interp_order").map(|s| s.to_lowercase()) {
+        Some(s) if s == "cubic" => InterpOrder::Cubic,
+        Some(s) if s == "linear" => InterpOrder::Linear,
+        _ => def.interp_order,
+    };
+    let mesh_type = match map.remove("mesh_type").map(|s| s.to_lowercase()) {
+        Some(s) if s == "internalcamexternalring" => MeshType::InternalCamExternalRing,
+        Some(s) if s == "externalcaminternalring" => MeshType::ExternalCamInternalRing,
+        _ => def.mesh_type,
+    };
 
     let params = LitvinParameters {
         up_fraction,
@@ -392,8 +578,29 @@ fn map_to_litvin_parameters(mut map: HashMap<String, String>) -> FEAResult<Litvi
         center_distance_scale,
         arc_residual_tol_mm,
         max_iter,
+        min_module,
+        export_cartesian_curves,
+        interp_order,
+        output_decimals,
+        compute_journal_geometry,
+        reverse_rotation,
+        min_clearance_target,
+        max_sliding_velocity,
+        mean_piston_speed_mps,
+        nvh_amp_limit,
+        build_time_budget_ms,
+        accel_limit,
+        jerk_limit,
+        nvh_cycles,
+        ramp_after_tdc_profile,
+        ramp_before_bdc_profile,
+        ramp_after_bdc_profile,
+        ramp_before_tdc_profile,
+        capture_iterates,
+        phi_refine_steps,
+        mesh_type,
     };
-    params.validate().map_err(|e| crate::error::FEAError::JNI(e))?;
+    params.validate().map_err(crate::error::FEAError::ParameterValidation)?;
     Ok(params)
 }
 
@@ -407,10 +614,10 @@ fn ensure_tmp_dir_for_id(id: jlong) -> std::path::PathBuf {
     dir
 }
 
-fn write_pitch_curves_json(path: &Path, curves: &PitchCurves) -> std::io::Result<()> {
-    use std::time::Instant;
-    let t0 = Instant::now();
-    let json = serde_json::json!({
+/// Build the pitch curves JSON; with `cartesian` the `xCam`/`yCam` (cam frame, vs theta)
+/// and `xRing`/`yRing` (ring frame, vs phi) arrays are added next to the polar radii.
+fn pitch_curves_to_json(curves: &PitchCurves, cartesian: bool) -> serde_json::Value {
+    let mut json = serde_json::json!({
         "theta": curves.theta_deg,
         "rCam": curves.r_cam,
         "phi": curves.phi_deg,
@@ -418,7 +625,45 @@ fn write_pitch_curves_json(path: &Path, curves: &PitchCurves) -> std::io::Result
         "sCam": curves.s_cam,
         "sRing": curves.s_ring,
         "phiOfTheta": curves.phi_of_theta_deg,
+        "rRingOfTheta": curves.r_ring_of_theta,
+        "phaseLagDeg": curves.phase_lag_deg(),
     });
+    if cartesian {
+        let xy = curves.cartesian();
+        json["xCam"] = serde_json::json!(xy.x_cam);
+        json["yCam"] = serde_json::json!(xy.y_cam);
+        json["xRing"] = serde_json::json!(xy.x_ring);
+        json["yRing"] = serde_json::json!(xy.y_ring);
+    }
+    json
+}
+
+/// Round every float inside JSON arrays to `decimals` places; scalar fields keep full precision.
+/// `None` leaves the value untouched.
+fn round_json_arrays(value: &mut serde_json::Value, decimals: Option<u32>) {
+    let Some(d) = decimals else { return };
+    let factor = 10f64.powi(d as i32);
+    fn walk(v: &mut serde_json::Value, factor: f64, in_array: bool) {
+        match v {
+            serde_json::Value::Number(n) if in_array && n.is_f64() => {
+                let x = n.as_f64().unwrap_or_default();
+                if let Some(r) = serde_json::Number::from_f64((x * factor).round() / factor) {
+                    *n = r;
+                }
+            }
+            serde_json::Value::Array(items) => items.iter_mut().for_each(|item| walk(item, factor, true)),
+            serde_json::Value::Object(map) => map.values_mut().for_each(|item| walk(item, factor, false)),
+            _ => {}
+        }
+    }
+    walk(value, factor, false);
+}
+
+fn write_pitch_curves_json(path: &Path, curves: &PitchCurves, cartesian: bool, decimals: Option<u32>) -> std::io::Result<()> {
+    use std::time::Instant;
+    let t0 = Instant::now();
+    let mut json = pitch_curves_to_json(curves, cartesian);
+    round_json_arrays(&mut json, decimals);
     let mut file = File::create(path)?;
     let data = serde_json::to_string_pretty(&json).unwrap();
     file.write_all(data.as_bytes())?;
@@ -427,6 +672,141 @@ fn write_pitch_curves_json(path: &Path, curves: &PitchCurves) -> std::io::Result
     Ok(())
 }
 
+/// Keys accepted by `getLitvinDiagnosticScalarNative`, named as in the diagnostics JSON.
+/// `arcResidualMax` is also accepted as the documented alias of `arcLengthResidualMax`.
+const DIAGNOSTIC_SCALAR_KEYS: &[&str] = &[
+    "arcLengthResidualMax",
+    "arcLengthResidualRms",
+    "iterCount",
+    "clearanceMin",
+    "envelopeClearanceMin",
+    "toothThicknessMin",
+    "curvatureRadiusMin",
+    "feasibleToothCount",
+    "camMeanRadius",
+    "ringMeanRadius",
+    "trackingRms",
+    "accelMax",
+    "jerkMax",
+    "achievedStroke",
+    "strokeError",
+    "spinClosureError",
+    "slidingVelMean",
+    "slidingVelMax",
+    "meshFrequencyHz",
+    "suggestedCenterDistanceInflation",
+    "buildMs",
+];
+
+/// Single diagnostics scalar by its JSON key (see [`DIAGNOSTIC_SCALAR_KEYS`]); `None` for unknown keys
+fn diagnostic_scalar(diag: &Diagnostics, key: &str) -> Option<f64> {
+    let value = match key {
+        "arcLengthResidualMax" | "arcResidualMax" => diag.arc_length_residual_max,
+        "arcLengthResidualRms" => diag.arc_length_residual_rms,
+        "iterCount" => diag.iter_count as f64,
+        "clearanceMin" => diag.clearance_min,
+        "envelopeClearanceMin" => diag.envelope_clearance_min,
+        "toothThicknessMin" => diag.tooth_thickness_min,
+        "curvatureRadiusMin" => diag.curvature_radius_min,
+        "feasibleToothCount" => diag.feasible_tooth_count as f64,
+        "camMeanRadius" => diag.cam_mean_radius,
+        "ringMeanRadius" => diag.ring_mean_radius,
+        "trackingRms" => diag.tracking_rms,
+        "accelMax" => diag.accel_max,
+        "jerkMax" => diag.jerk_max,
+        "achievedStroke" => diag.achieved_stroke,
+        "strokeError" => diag.stroke_error,
+        "spinClosureError" => diag.spin_closure_error,
+        "slidingVelMean" => diag.sliding_vel_mean,
+        "slidingVelMax" => diag.sliding_vel_max,
+        "meshFrequencyHz" => diag.mesh_frequency_hz,
+        "suggestedCenterDistanceInflation" => diag.suggested_center_distance_inflation,
+        "buildMs" => diag.build_ms,
+        _ => return None,
+    };
+    Some(value)
+}
+
+/// Build the diagnostics block of the kinematics tables JSON
+///
+/// Includes a `"units"` object mapping each metric to its unit string; values are unchanged.
+fn diagnostics_to_json(diag: &Diagnostics) -> serde_json::Value {
+    let units: serde_json::Map<String, serde_json::Value> = litvin::DIAGNOSTIC_UNITS
+        .iter()
+        .map(|(k, u)| (k.to_string(), serde_json::Value::from(*u)))
+        .collect();
+    serde_json::json!({
+        "version": "1.0",
+        "arcLengthResidualMax": diag.arc_length_residual_max,
+        "arcLengthResidualRms": diag.arc_length_residual_rms,
+        "iterCount": diag.iter_count,
+        "usedMaxIter": diag.used_max_iter,
+        "regularizationApplied": diag.regularization_applied,
+        "clearanceMin": diag.clearance_min,
+        "clearanceViolations": diag.clearance_violations.iter().map(|v| serde_json::json!({
+            "alphaStartDeg": v.alpha_start_deg,
+            "alphaEndDeg": v.alpha_end_deg,
+            "minClearance": v.min_clearance,
+            "severity": v.severity,
+            "timeStartS": v.time_start_s,
+            "timeEndS": v.time_end_s,
+        })).collect::<Vec<_>>(),
+        "clearancePass": diag.clearance_pass,
+        "envelopeClearanceMin": diag.envelope_clearance_min,
+        "envelopeViolations": diag.envelope_violations.iter().map(|v| serde_json::json!({
+            "alphaStartDeg": v.alpha_start_deg,
+            "alphaEndDeg": v.alpha_end_deg,
+            "minClearance": v.min_clearance,
+            "severity": v.severity,
+            "timeStartS": v.time_start_s,
+            "timeEndS": v.time_end_s,
+        })).collect::<Vec<_>>(),
+        "toothThicknessMin": diag.tooth_thickness_min,
+        "undercutFlag": diag.undercut_flag,
+        "curvatureRadiusMin": diag.curvature_radius_min,
+        "feasibleToothCount": diag.feasible_tooth_count,
+        "toothCountInfeasible": diag.tooth_count_infeasible,
+        "camMeanRadius": diag.cam_mean_radius,
+        "ringMeanRadius": diag.ring_mean_radius,
+        "trackingRms": diag.tracking_rms,
+        "accelMax": diag.accel_max,
+        "jerkMax": diag.jerk_max,
+        "accelPass": diag.accel_pass,
+        "jerkPass": diag.jerk_pass,
+        "achievedStroke": diag.achieved_stroke,
+        "strokeError": diag.stroke_error,
+        "degenerate": diag.degenerate,
+        "motionLawAccelMismatch": diag.motion_law_accel_mismatch,
+        "pointsPerShortestRamp": diag.points_per_shortest_ramp,
+        "spinClosureError": diag.spin_closure_error,
+        "slidingVelMean": diag.sliding_vel_mean,
+        "slidingVelMax": diag.sliding_vel_max,
+        "slidingVelocityPass": diag.sliding_velocity_pass,
+        "slidingVelocityExceededAlphaDeg": diag.sliding_velocity_exceeded_alpha_deg,
+        "nvhPeaks": diag.nvh_peaks.iter().map(|p| serde_json::json!({
+            "freqHz": p.freq_hz,
+            "amp": p.amp
+        })).collect::<Vec<_>>(),
+        "dominantNvhOrder": diag.dominant_nvh_order,
+        "dominantNvhAmp": diag.dominant_nvh_amp,
+        "nvhLimitFraction": diag.nvh_limit_fraction,
+        "meshFrequencyHz": diag.mesh_frequency_hz,
+        "nvhAtMeshFrequency": diag.nvh_at_mesh_frequency,
+        "iterateSnapshots": diag.iterate_snapshots,
+        "notes": diag.notes,
+        "suggestedCenterDistanceInflation": diag.suggested_center_distance_inflation,
+        "buildMs": diag.build_ms,
+        "overBudget": diag.over_budget,
+        "timing": diag.timing,
+        "units": units,
+    })
+}
+
+/// Journal arrays are either one entry per alpha or empty (`compute_journal_geometry = false`).
+fn journal_len_ok(p: &PlanetState, n: usize) -> bool {
+    p.journal_x.len() == p.journal_y.len() && (p.journal_x.len() == n || p.journal_x.is_empty())
+}
+
 fn write_tables_json(path: &Path, tables: &LitvinTables) -> std::io::Result<()> {
     use std::time::Instant;
     let t0 = Instant::now();
@@ -434,8 +814,8 @@ fn write_tables_json(path: &Path, tables: &LitvinTables) -> std::io::Result<()>
     // Sanity: all per-planet arrays must equal alpha_deg length
     let n = tables.alpha_deg.len();
     for (pi, p) in tables.planets.iter().enumerate() {
-        if p.center_x.len() != n || p.center_y.len() != n || p.spin_psi_deg.len() != n
-            || p.journal_x.len() != n || p.journal_y.len() != n || p.piston_s.len() != n {
+        if p.center_x.len() != n || p.center_y.len() != n || p.spin_psi_deg.len() != n || p.spin_psi_deg_unwrapped.len() != n
+            || !journal_len_ok(p, n) || p.piston_s.len() != n {
             return Err(std::io::Error::new(
                 std::io::ErrorKind::InvalidData,
                 format!("LitvinTables planet {} arrays length mismatch with alphaDeg ({}).", pi, n)
@@ -443,17 +823,29 @@ fn write_tables_json(path: &Path, tables: &LitvinTables) -> std::io::Result<()>
         }
     }
 
+    let json = tables_to_json(tables);
+    validate_tables_value(&json).map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))?;
+    let mut file = File::create(path)?;
+    let data = serde_json::to_string_pretty(&json).unwrap();
+    file.write_all(data.as_bytes())?;
+    let ms = t0.elapsed().as_secs_f64() * 1000.0;
+    println!("[PERF][JNI] write_tables_json: bytes={}, ms={:.3}", data.len(), ms);
+    Ok(())
+}
+
+/// Build the full kinematics tables JSON written by [`write_tables_json`]
+fn tables_to_json(tables: &LitvinTables) -> serde_json::Value {
     let planets: Vec<serde_json::Value> = tables.planets.iter().map(|p| serde_json::json!({
         "centerX": p.center_x,
         "centerY": p.center_y,
         "spinPsiDeg": p.spin_psi_deg,
+        "spinPsiDegUnwrapped": p.spin_psi_deg_unwrapped,
         "journalX": p.journal_x,
         "journalY": p.journal_y,
         "pistonS": p.piston_s,
     })).collect();
 
-    let diag = &tables.diagnostics;
-    let json = serde_json::json!({
+    let mut json = serde_json::json!({
         // Force params to be a flat object; fallback to {} to preserve shape
         "params": match serde_json::to_value(&tables.params) {
             Ok(v) if v.is_object() => v,
@@ -465,47 +857,56 @@ fn write_tables_json(path: &Path, tables: &LitvinTables) -> std::io::Result<()>
         "curves": {
             "phiOfTheta": tables.curves.phi_of_theta_deg,
         },
-        "diagnostics": {
-            "version": "1.0",
-            "arcLengthResidualMax": diag.arc_length_residual_max,
-            "arcLengthResidualRms": diag.arc_length_residual_rms,
-            "iterCount": diag.iter_count,
-            "usedMaxIter": diag.used_max_iter,
-            "regularizationApplied": diag.regularization_applied,
-            "clearanceMin": diag.clearance_min,
-            "clearanceViolations": diag.clearance_violations.iter().map(|v| serde_json::json!({
-                "alphaStartDeg": v.alpha_start_deg,
-                "alphaEndDeg": v.alpha_end_deg,
-                "minClearance": v.min_clearance,
-            })).collect::<Vec<_>>(),
-            "envelopeClearanceMin": diag.envelope_clearance_min,
-            "envelopeViolations": diag.envelope_violations.iter().map(|v| serde_json::json!({
-                "alphaStartDeg": v.alpha_start_deg,
-                "alphaEndDeg": v.alpha_end_deg,
-                "minClearance": v.min_clearance,
-            })).collect::<Vec<_>>(),
-            "toothThicknessMin": diag.tooth_thickness_min,
-            "undercutFlag": diag.undercut_flag,
-            "curvatureRadiusMin": diag.curvature_radius_min,
-            "trackingRms": diag.tracking_rms,
-            "accelMax": diag.accel_max,
-            "jerkMax": diag.jerk_max,
-            "slidingVelMean": diag.sliding_vel_mean,
-            "slidingVelMax": diag.sliding_vel_max,
-            "nvhPeaks": diag.nvh_peaks.iter().map(|p| serde_json::json!({
-                "freqHz": p.freq_hz,
-                "amp": p.amp
-            })).collect::<Vec<_>>(),
-            "notes": diag.notes,
-            "suggestedCenterDistanceInflation": diag.suggested_center_distance_inflation,
-            "buildMs": diag.build_ms
-        }
+        "diagnostics": diagnostics_to_json(&tables.diagnostics)
     });
-    let mut file = File::create(path)?;
-    let data = serde_json::to_string_pretty(&json).unwrap();
-    file.write_all(data.as_bytes())?;
-    let ms = t0.elapsed().as_secs_f64() * 1000.0;
-    println!("[PERF][JNI] write_tables_json: bytes={}, ms={:.3}", data.len(), ms);
+    round_json_arrays(&mut json, tables.params.output_decimals);
+    json
+}
+
+/// Per-planet arrays of the tables JSON; each has one entry per `alphaDeg`
+const TABLES_PLANET_KEYS: &[&str] = &["centerX", "centerY", "spinPsiDeg", "spinPsiDegUnwrapped", "journalX", "journalY", "pistonS"];
+
+/// Check a kinematics tables JSON document against the schema consumed on the Kotlin side.
+///
+/// Requires the `params`, `curves` and `diagnostics` objects and the `alphaDeg` and `planets`
+/// arrays, every planet array in [`TABLES_PLANET_KEYS`] and `curves.phiOfTheta` to match the
+/// `alphaDeg` length (the journal arrays may instead be empty), and `diagnostics.version`.
+/// Unparseable input is a `Deserialization` error, a schema violation a `Serialization` error.
+pub fn validate_tables_json(json: &str) -> FEAResult<()> {
+    let value: serde_json::Value = serde_json::from_str(json).map_err(|e| FEAError::Deserialization(e.to_string()))?;
+    validate_tables_value(&value).map_err(FEAError::Serialization)
+}
+
+fn validate_tables_value(json: &serde_json::Value) -> Result<(), String> {
+    for key in ["params", "curves", "diagnostics"] {
+        if !json[key].is_object() {
+            return Err(format!("missing object `{}`", key));
+        }
+    }
+    let n = json["alphaDeg"].as_array().ok_or("missing array `alphaDeg`")?.len();
+    let array_len = |v: &serde_json::Value, path: &str| -> Result<usize, String> {
+        v.as_array().map(|a| a.len()).ok_or_else(|| format!("missing array `{}`", path))
+    };
+    let planets = json["planets"].as_array().ok_or("missing array `planets`")?;
+    if planets.is_empty() {
+        return Err("`planets` is empty".to_string());
+    }
+    for (i, planet) in planets.iter().enumerate() {
+        for key in TABLES_PLANET_KEYS {
+            let len = array_len(&planet[*key], &format!("planets[{}].{}", i, key))?;
+            let optional = key.starts_with("journal") && len == 0;
+            if len != n && !optional {
+                return Err(format!("planets[{}].{} has {} entries, alphaDeg has {}", i, key, len, n));
+            }
+        }
+    }
+    let len = array_len(&json["curves"]["phiOfTheta"], "curves.phiOfTheta")?;
+    if len != n {
+        return Err(format!("curves.phiOfTheta has {} entries, alphaDeg has {}", len, n));
+    }
+    if json["diagnostics"]["version"].as_str().is_none() {
+        return Err("missing string `diagnostics.version`".to_string());
+    }
     Ok(())
 }
 
@@ -524,27 +925,27 @@ fn write_state_json(path: &Path, alpha_deg: f64, tables: &LitvinTables) -> std::
         debug_assert_eq!(p.center_x.len(), expected_len, "center_x length mismatch for planet {}", pi);
         debug_assert_eq!(p.center_y.len(), expected_len, "center_y length mismatch for planet {}", pi);
         debug_assert_eq!(p.spin_psi_deg.len(), expected_len, "spin_psi_deg length mismatch for planet {}", pi);
-        debug_assert_eq!(p.journal_x.len(), expected_len, "journal_x length mismatch for planet {}", pi);
-        debug_assert_eq!(p.journal_y.len(), expected_len, "journal_y length mismatch for planet {}", pi);
+        debug_assert_eq!(p.spin_psi_deg_unwrapped.len(), expected_len, "spin_psi_deg_unwrapped length mismatch for planet {}", pi);
+        debug_assert!(journal_len_ok(p, expected_len), "journal length mismatch for planet {}", pi);
         debug_assert_eq!(p.piston_s.len(), expected_len, "piston_s length mismatch for planet {}", pi);
     }
 
     let mut center_x = Vec::with_capacity(tables.planets.len());
     let mut center_y = Vec::with_capacity(tables.planets.len());
     let mut spin = Vec::with_capacity(tables.planets.len());
-    let mut jx = Vec::with_capacity(tables.planets.len());
-    let mut jy = Vec::with_capacity(tables.planets.len());
+    let mut jx: Vec<f64> = Vec::with_capacity(tables.planets.len());
+    let mut jy: Vec<f64> = Vec::with_capacity(tables.planets.len());
     let mut pist = Vec::with_capacity(tables.planets.len());
     for p in &tables.planets {
         center_x.push(p.center_x[idx]);
         center_y.push(p.center_y[idx]);
         spin.push(p.spin_psi_deg[idx]);
-        jx.push(p.journal_x[idx]);
-        jy.push(p.journal_y[idx]);
+        jx.extend(p.journal_x.get(idx));
+        jy.extend(p.journal_y.get(idx));
         pist.push(p.piston_s[idx]);
     }
 
-    let json = serde_json::json!({
+    let mut json = serde_json::json!({
         "alphaDeg": tables.alpha_deg[idx],
         // Optional context fields (safe additions per DTO stability policy)
         "requestedAlphaDeg": alpha_deg,
@@ -556,6 +957,7 @@ fn write_state_json(path: &Path, alpha_deg: f64, tables: &LitvinTables) -> std::
         "journalY": jy,
         "pistonS": pist,
     });
+    round_json_arrays(&mut json, tables.params.output_decimals);
     let mut file = File::create(path)?;
     let data = serde_json::to_string_pretty(&json).unwrap();
     file.write_all(data.as_bytes())?;
@@ -577,7 +979,7 @@ pub extern "system" fn Java_com_campro_v5_animation_LitvinNative_createLitvinLaw
 ) -> jlong {
     let res = string_array_to_map(&mut env, parameters)
         .and_then(map_to_litvin_parameters)
-        .and_then(|p| litvin::build_litvin_tables(&p).map_err(|e| crate::error::FEAError::JNI(e)));
+        .and_then(|p| litvin::build_litvin_tables(&p));
     match res {
         Ok(tables) => {
             let id = get_next_id();
@@ -586,7 +988,7 @@ pub extern "system" fn Java_com_campro_v5_animation_LitvinNative_createLitvinLaw
             let _ = ensure_tmp_dir_for_id(id);
             id
         }
-        Err(e) => { let _ = env.throw(format!("Failed to create Litvin law: {}", e)); 0 }
+        Err(e) => { throw_fea_error(&mut env, "Failed to create Litvin law", &e); 0 }
     }
 }
 
@@ -599,10 +1001,10 @@ pub extern "system" fn Java_com_campro_v5_animation_LitvinNative_updateLitvinLaw
 ) {
     let res = string_array_to_map(&mut env, parameters)
         .and_then(map_to_litvin_parameters)
-        .and_then(|p| litvin::build_litvin_tables(&p).map_err(|e| crate::error::FEAError::JNI(e)));
+        .and_then(|p| litvin::build_litvin_tables(&p));
     match res {
         Ok(tables) => { LITVIN_TABLES.lock().unwrap().insert(id, Arc::new(tables)); }
-        Err(e) => { let _ = env.throw(format!("Failed to update Litvin law: {}", e)); }
+        Err(e) => { throw_fea_error(&mut env, "Failed to update Litvin law", &e); }
     }
 }
 
@@ -616,13 +1018,13 @@ pub extern "system" fn Java_com_campro_v5_animation_LitvinNative_getLitvinPitchC
         Ok(tables) => {
             let dir = ensure_tmp_dir_for_id(id);
             let path = dir.join("pitch_curves.json");
-            if let Err(e) = write_pitch_curves_json(&path, &tables.curves) {
-                let _ = env.throw(format!("Failed to write pitch curves JSON: {}", e));
+            if let Err(e) = write_pitch_curves_json(&path, &tables.curves, tables.params.export_cartesian_curves, tables.params.output_decimals) {
+                throw_fea_error(&mut env, "Failed to write pitch curves JSON", &FEAError::from(e));
                 return std::ptr::null_mut();
             }
             env.new_string(path.to_string_lossy().to_string()).map(|s| s.into_raw()).unwrap_or(std::ptr::null_mut())
         }
-        Err(e) => { let _ = env.throw(format!("Failed to get Litvin tables: {}", e)); std::ptr::null_mut() }
+        Err(e) => { throw_fea_error(&mut env, "Failed to get Litvin tables", &e); std::ptr::null_mut() }
     }
 }
 
@@ -637,12 +1039,12 @@ pub extern "system" fn Java_com_campro_v5_animation_LitvinNative_getLitvinKinema
             let dir = ensure_tmp_dir_for_id(id);
             let path = dir.join("litvin_tables.json");
             if let Err(e) = write_tables_json(&path, &tables) {
-                let _ = env.throw(format!("Failed to write kinematics tables JSON: {}", e));
+                throw_fea_error(&mut env, "Failed to write kinematics tables JSON", &FEAError::from(e));
                 return std::ptr::null_mut();
             }
             env.new_string(path.to_string_lossy().to_string()).map(|s| s.into_raw()).unwrap_or(std::ptr::null_mut())
         }
-        Err(e) => { let _ = env.throw(format!("Failed to get Litvin tables: {}", e)); std::ptr::null_mut() }
+        Err(e) => { throw_fea_error(&mut env, "Failed to get Litvin tables", &e); std::ptr::null_mut() }
     }
 }
 
@@ -658,20 +1060,131 @@ pub extern "system" fn Java_com_campro_v5_animation_LitvinNative_getLitvinSystem
             let dir = ensure_tmp_dir_for_id(id);
             let path = dir.join("state.json");
             if let Err(e) = write_state_json(&path, alpha_deg, &tables) {
-                let _ = env.throw(format!("Failed to write system state JSON: {}", e));
+                throw_fea_error(&mut env, "Failed to write system state JSON", &FEAError::from(e));
                 return std::ptr::null_mut();
             }
             env.new_string(path.to_string_lossy().to_string()).map(|s| s.into_raw()).unwrap_or(std::ptr::null_mut())
         }
-        Err(e) => { let _ = env.throw(format!("Failed to get Litvin tables: {}", e)); std::ptr::null_mut() }
+        Err(e) => { throw_fea_error(&mut env, "Failed to get Litvin tables", &e); std::ptr::null_mut() }
+    }
+}
+
+/// System state at `alpha_deg`, linearly interpolated between the bracketing table samples.
+/// Spin angles are interpolated along the shorter arc so the 360°→0° wrap does not sweep back.
+fn interpolated_state_json(tables: &LitvinTables, alpha_deg: f64) -> serde_json::Value {
+    let n = tables.alpha_deg.len();
+    let step = if n > 1 { tables.alpha_deg[1] - tables.alpha_deg[0] } else { 1.0 };
+    let alpha = alpha_deg.rem_euclid(360.0);
+    let pos = if step > 0.0 { alpha / step } else { 0.0 };
+    let i0 = (pos.floor() as usize) % n.max(1);
+    let i1 = (i0 + 1) % n.max(1);
+    let w = pos - pos.floor();
+    let lerp = |v: &[f64]| v[i0] + (v[i1] - v[i0]) * w;
+    let lerp_deg = |v: &[f64]| {
+        let d = (v[i1] - v[i0] + 180.0).rem_euclid(360.0) - 180.0;
+        (v[i0] + d * w).rem_euclid(360.0)
+    };
+
+    let mut json = serde_json::json!({
+        "alphaDeg": alpha,
+        "centerX": tables.planets.iter().map(|p| lerp(&p.center_x)).collect::<Vec<_>>(),
+        "centerY": tables.planets.iter().map(|p| lerp(&p.center_y)).collect::<Vec<_>>(),
+        "spinPsiDeg": tables.planets.iter().map(|p| lerp_deg(&p.spin_psi_deg)).collect::<Vec<_>>(),
+        "journalX": tables.planets.iter().filter(|p| !p.journal_x.is_empty()).map(|p| lerp(&p.journal_x)).collect::<Vec<_>>(),
+        "journalY": tables.planets.iter().filter(|p| !p.journal_y.is_empty()).map(|p| lerp(&p.journal_y)).collect::<Vec<_>>(),
+        "pistonS": tables.planets.iter().map(|p| lerp(&p.piston_s)).collect::<Vec<_>>(),
+    });
+    round_json_arrays(&mut json, tables.params.output_decimals);
+    json
+}
+
+/// Interpolated system states at each of `alphas_deg`, in request order.
+fn interpolated_states_json(tables: &LitvinTables, alphas_deg: &[f64]) -> serde_json::Value {
+    serde_json::Value::Array(alphas_deg.iter().map(|&a| interpolated_state_json(tables, a)).collect())
+}
+
+/// Advance the playback alpha of `id` by `dt_seconds` at `rpm` (6·rpm deg/s), wrapping to [0, 360).
+/// An ID without a stored alpha starts from 0°.
+fn advance_litvin_alpha(id: jlong, dt_seconds: f64, rpm: f64) -> f64 {
+    let mut map = LITVIN_ALPHA.lock().unwrap();
+    let alpha = map.entry(id).or_insert(0.0);
+    *alpha = (*alpha + 6.0 * rpm * dt_seconds).rem_euclid(360.0);
+    *alpha
+}
+
+/// Set the playback alpha of `id`, wrapped to [0, 360).
+fn reset_litvin_alpha(id: jlong, alpha_deg: f64) -> f64 {
+    let alpha = alpha_deg.rem_euclid(360.0);
+    LITVIN_ALPHA.lock().unwrap().insert(id, alpha);
+    alpha
+}
+
+#[no_mangle]
+pub extern "system" fn Java_com_campro_v5_animation_LitvinNative_advanceLitvinStateNative(
+    mut env: JNIEnv,
+    _class: JClass,
+    id: jlong,
+    dt_seconds: jdouble,
+) -> jstring {
+    match get_litvin_tables(id) {
+        Ok(tables) => {
+            let alpha = advance_litvin_alpha(id, dt_seconds, tables.params.rpm);
+            let json = interpolated_state_json(&tables, alpha).to_string();
+            env.new_string(json).map(|s| s.into_raw()).unwrap_or(std::ptr::null_mut())
+        }
+        Err(e) => { throw_fea_error(&mut env, "Failed to get Litvin tables", &e); std::ptr::null_mut() }
     }
 }
 
+#[no_mangle]
+pub extern "system" fn Java_com_campro_v5_animation_LitvinNative_resetLitvinStateNative(
+    mut env: JNIEnv,
+    _class: JClass,
+    id: jlong,
+    alpha_deg: jdouble,
+) {
+    if let Err(e) = get_litvin_tables(id) {
+        throw_fea_error(&mut env, "Failed to get Litvin tables", &e);
+        return;
+    }
+    reset_litvin_alpha(id, alpha_deg);
+}
+
+#[no_mangle]
+pub extern "system" fn Java_com_campro_v5_animation_LitvinNative_getLitvinStatesNative(
+    mut env: JNIEnv,
+    _class: JClass,
+    id: jlong,
+    alphas: jdoubleArray,
+) -> jstring {
+    let tables = match get_litvin_tables(id) {
+        Ok(t) => t,
+        Err(e) => { throw_fea_error(&mut env, "Failed to get Litvin tables", &e); return std::ptr::null_mut(); }
+    };
+    let alphas_deg = match double_array_to_vec(&mut env, alphas) {
+        Ok(v) => v,
+        Err(e) => { throw_fea_error(&mut env, "Failed to read alphas array", &e); return std::ptr::null_mut(); }
+    };
+    let json = interpolated_states_json(&tables, &alphas_deg).to_string();
+    env.new_string(json).map(|s| s.into_raw()).unwrap_or(std::ptr::null_mut())
+}
+
+fn write_journal_envelope_json(path: &Path, tables: &LitvinTables) -> std::io::Result<()> {
+    let (x, y): (Vec<f64>, Vec<f64>) = tables.journal_envelope().into_iter().unzip();
+    let mut json = serde_json::json!({
+        "envelopeX": x,
+        "envelopeY": y,
+    });
+    round_json_arrays(&mut json, tables.params.output_decimals);
+    let mut file = File::create(path)?;
+    file.write_all(serde_json::to_string_pretty(&json).unwrap().as_bytes())
+}
+
 fn write_boundary_json(path: &Path, tables: &LitvinTables) -> std::io::Result<()> {
     // Export only what's needed for FEA boundary conditions
     let n = tables.alpha_deg.len();
     for (pi, p) in tables.planets.iter().enumerate() {
-        if p.journal_x.len() != n || p.journal_y.len() != n || p.piston_s.len() != n {
+        if !journal_len_ok(p, n) || p.piston_s.len() != n {
             return Err(std::io::Error::new(
                 std::io::ErrorKind::InvalidData,
                 format!("Boundary planet {} arrays length mismatch with alphaDeg ({}).", pi, n)
@@ -683,10 +1196,11 @@ fn write_boundary_json(path: &Path, tables: &LitvinTables) -> std::io::Result<()
         "journalY": p.journal_y,
         "pistonS": p.piston_s,
     })).collect();
-    let json = serde_json::json!({
+    let mut json = serde_json::json!({
         "alphaDeg": tables.alpha_deg,
         "planets": planets,
     });
+    round_json_arrays(&mut json, tables.params.output_decimals);
     let mut file = File::create(path)?;
     file.write_all(serde_json::to_string_pretty(&json).unwrap().as_bytes())
 }
@@ -702,12 +1216,32 @@ pub extern "system" fn Java_com_campro_v5_animation_LitvinNative_getLitvinFeaBou
             let dir = ensure_tmp_dir_for_id(id);
             let path = dir.join("litvin_fea_boundary.json");
             if let Err(e) = write_boundary_json(&path, &tables) {
-                let _ = env.throw(format!("Failed to write FEA boundary JSON: {}", e));
+                throw_fea_error(&mut env, "Failed to write FEA boundary JSON", &FEAError::from(e));
                 return std::ptr::null_mut();
             }
             env.new_string(path.to_string_lossy().to_string()).map(|s| s.into_raw()).unwrap_or(std::ptr::null_mut())
         }
-        Err(e) => { let _ = env.throw(format!("Failed to get Litvin tables: {}", e)); std::ptr::null_mut() }
+        Err(e) => { throw_fea_error(&mut env, "Failed to get Litvin tables", &e); std::ptr::null_mut() }
+    }
+}
+
+#[no_mangle]
+pub extern "system" fn Java_com_campro_v5_animation_LitvinNative_getLitvinJournalEnvelopeNative(
+    mut env: JNIEnv,
+    _class: JClass,
+    id: jlong,
+) -> jstring {
+    match get_litvin_tables(id) {
+        Ok(tables) => {
+            let dir = ensure_tmp_dir_for_id(id);
+            let path = dir.join("litvin_journal_envelope.json");
+            if let Err(e) = write_journal_envelope_json(&path, &tables) {
+                throw_fea_error(&mut env, "Failed to write journal envelope JSON", &FEAError::from(e));
+                return std::ptr::null_mut();
+            }
+            env.new_string(path.to_string_lossy().to_string()).map(|s| s.into_raw()).unwrap_or(std::ptr::null_mut())
+        }
+        Err(e) => { throw_fea_error(&mut env, "Failed to get Litvin tables", &e); std::ptr::null_mut() }
     }
 }
 
@@ -737,8 +1271,9 @@ pub extern "system" fn Java_com_campro_v5_animation_LitvinNative_disposeLitvinLa
 ) {
     let mut map = LITVIN_TABLES.lock().unwrap();
     if map.remove(&id).is_none() {
-        let _ = env.throw(format!("Litvin law with ID {} not found", id));
+        throw_fea_error(&mut env, "Failed to dispose Litvin law", &FEAError::JNI(format!("Litvin law with ID {} not found", id)));
     }
+    LITVIN_ALPHA.lock().unwrap().remove(&id);
     // cleanup temp dir
     if let Some(dir) = LITVIN_TMPDIRS.lock().unwrap().remove(&id) {
         let _ = std::fs::remove_dir_all(dir);
@@ -775,7 +1310,44 @@ pub extern "system" fn Java_com_campro_v5_animation_LitvinNative_runDiagnosticsN
             let s = serde_json::to_string(&json).unwrap_or_else(|_| "{}".to_string());
             env.new_string(s).map(|s| s.into_raw()).unwrap_or(std::ptr::null_mut())
         }
-        Err(e) => { let _ = env.throw(format!("Failed to get Litvin tables: {}", e)); std::ptr::null_mut() }
+        Err(e) => { throw_fea_error(&mut env, "Failed to get Litvin tables", &e); std::ptr::null_mut() }
+    }
+}
+
+/// Return the full diagnostics of a Litvin law as a JSON string (no file I/O).
+#[no_mangle]
+pub extern "system" fn Java_com_campro_v5_animation_LitvinNative_getLitvinDiagnosticsJsonNative(
+    mut env: JNIEnv,
+    _class: JClass,
+    id: jlong,
+) -> jstring {
+    match get_litvin_tables(id) {
+        Ok(tables) => env.new_string(tables.diagnostics_json()).map(|s| s.into_raw()).unwrap_or(std::ptr::null_mut()),
+        Err(e) => { throw_fea_error(&mut env, "Failed to get Litvin tables", &e); std::ptr::null_mut() }
+    }
+}
+
+#[no_mangle]
+pub extern "system" fn Java_com_campro_v5_animation_LitvinNative_getLitvinDiagnosticScalarNative(
+    mut env: JNIEnv,
+    _class: JClass,
+    id: jlong,
+    key: JString,
+) -> jdouble {
+    let key: String = match env.get_string(&key) {
+        Ok(s) => s.into(),
+        Err(e) => { throw_fea_error(&mut env, "Invalid diagnostic key", &FEAError::JNI(e.to_string())); return 0.0; }
+    };
+    match get_litvin_tables(id) {
+        Ok(tables) => match diagnostic_scalar(&tables.diagnostics, &key) {
+            Some(value) => value,
+            None => {
+                let err = FEAError::ParameterValidation(format!("Unknown diagnostic key '{}' (expected one of {})", key, DIAGNOSTIC_SCALAR_KEYS.join(", ")));
+                throw_fea_error(&mut env, "Failed to get diagnostic scalar", &err);
+                0.0
+            }
+        },
+        Err(e) => { throw_fea_error(&mut env, "Failed to get Litvin tables", &e); 0.0 }
     }
 }
 
@@ -790,10 +1362,10 @@ pub extern "system" fn Java_com_campro_v5_fea_FeaEngine_runAnalysisNative(
     _parameters: jobjectArray,
 ) {
     let model: String = match env.get_string(&model_file_path) { Ok(s) => s.into(), Err(e) => {
-        let _ = env.throw(format!("runAnalysisNative: invalid model path: {}", e)); return;
+        throw_fea_error(&mut env, "runAnalysisNative: invalid model path", &FEAError::JNI(e.to_string())); return;
     }};
     let results: String = match env.get_string(&results_file_path) { Ok(s) => s.into(), Err(e) => {
-        let _ = env.throw(format!("runAnalysisNative: invalid results path: {}", e)); return;
+        throw_fea_error(&mut env, "runAnalysisNative: invalid results path", &FEAError::JNI(e.to_string())); return;
     }};
 
     let payload = serde_json::json!({
@@ -802,7 +1374,7 @@ pub extern "system" fn Java_com_campro_v5_fea_FeaEngine_runAnalysisNative(
         "metrics": { "placeHolder": true }
     });
     if let Err(e) = std::fs::write(&results, serde_json::to_string_pretty(&payload).unwrap()) {
-        let _ = env.throw(format!("runAnalysisNative: failed to write results: {}", e));
+        throw_fea_error(&mut env, "runAnalysisNative: failed to write results", &FEAError::from(e));
     }
 }
 
@@ -815,10 +1387,10 @@ pub extern "system" fn Java_com_campro_v5_fea_FeaEngine_runStressAnalysisNative(
     _parameters: jobjectArray,
 ) {
     let model: String = match env.get_string(&model_file_path) { Ok(s) => s.into(), Err(e) => {
-        let _ = env.throw(format!("runStressAnalysisNative: invalid model path: {}", e)); return;
+        throw_fea_error(&mut env, "runStressAnalysisNative: invalid model path", &FEAError::JNI(e.to_string())); return;
     }};
     let results: String = match env.get_string(&results_file_path) { Ok(s) => s.into(), Err(e) => {
-        let _ = env.throw(format!("runStressAnalysisNative: invalid results path: {}", e)); return;
+        throw_fea_error(&mut env, "runStressAnalysisNative: invalid results path", &FEAError::JNI(e.to_string())); return;
     }};
 
     let payload = serde_json::json!({
@@ -827,7 +1399,7 @@ pub extern "system" fn Java_com_campro_v5_fea_FeaEngine_runStressAnalysisNative(
         "stress": { "maxVonMises": 0.0, "placeHolder": true }
     });
     if let Err(e) = std::fs::write(&results, serde_json::to_string_pretty(&payload).unwrap()) {
-        let _ = env.throw(format!("runStressAnalysisNative: failed to write results: {}", e));
+        throw_fea_error(&mut env, "runStressAnalysisNative: failed to write results", &FEAError::from(e));
     }
 }
 
@@ -840,10 +1412,10 @@ pub extern "system" fn Java_com_campro_v5_fea_FeaEngine_runVibrationAnalysisNati
     _parameters: jobjectArray,
 ) {
     let model: String = match env.get_string(&model_file_path) { Ok(s) => s.into(), Err(e) => {
-        let _ = env.throw(format!("runVibrationAnalysisNative: invalid model path: {}", e)); return;
+        throw_fea_error(&mut env, "runVibrationAnalysisNative: invalid model path", &FEAError::JNI(e.to_string())); return;
     }};
     let results: String = match env.get_string(&results_file_path) { Ok(s) => s.into(), Err(e) => {
-        let _ = env.throw(format!("runVibrationAnalysisNative: invalid results path: {}", e)); return;
+        throw_fea_error(&mut env, "runVibrationAnalysisNative: invalid results path", &FEAError::JNI(e.to_string())); return;
     }};
 
     let payload = serde_json::json!({
@@ -853,7 +1425,7 @@ pub extern "system" fn Java_com_campro_v5_fea_FeaEngine_runVibrationAnalysisNati
         "placeHolder": true
     });
     if let Err(e) = std::fs::write(&results, serde_json::to_string_pretty(&payload).unwrap()) {
-        let _ = env.throw(format!("runVibrationAnalysisNative: failed to write results: {}", e));
+        throw_fea_error(&mut env, "runVibrationAnalysisNative: failed to write results", &FEAError::from(e));
     }
 }
 
@@ -866,10 +1438,10 @@ pub extern "system" fn Java_com_campro_v5_fea_FeaEngine_generateMeshNative(
     _parameters: jobjectArray,
 ) {
     let model: String = match env.get_string(&model_file_path) { Ok(s) => s.into(), Err(e) => {
-        let _ = env.throw(format!("generateMeshNative: invalid model path: {}", e)); return;
+        throw_fea_error(&mut env, "generateMeshNative: invalid model path", &FEAError::JNI(e.to_string())); return;
     }};
     let mesh: String = match env.get_string(&mesh_file_path) { Ok(s) => s.into(), Err(e) => {
-        let _ = env.throw(format!("generateMeshNative: invalid mesh path: {}", e)); return;
+        throw_fea_error(&mut env, "generateMeshNative: invalid mesh path", &FEAError::JNI(e.to_string())); return;
     }};
 
     let payload = serde_json::json!({
@@ -878,7 +1450,7 @@ pub extern "system" fn Java_com_campro_v5_fea_FeaEngine_generateMeshNative(
         "mesh": { "elements": 0, "nodes": 0, "placeHolder": true }
     });
     if let Err(e) = std::fs::write(&mesh, serde_json::to_string_pretty(&payload).unwrap()) {
-        let _ = env.throw(format!("generateMeshNative: failed to write mesh: {}", e));
+        throw_fea_error(&mut env, "generateMeshNative: failed to write mesh", &FEAError::from(e));
     }
 }
 
@@ -897,3 +1469,228 @@ pub extern "system" fn Java_com_campro_v5_fea_FeaEngine_getVersionNative(
 ) -> jstring {
     env.new_string(crate::VERSION).map(|s| s.into_raw()).unwrap_or(std::ptr::null_mut())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn flattened_columns_round_trip() {
+        let analysis = MotionLaw::new(MotionParameters::default()).unwrap().analyze_kinematics(721);
+        let flat = flatten_columns(&analysis);
+        let (count, rows) = (flat[0] as usize, flat[1] as usize);
+        assert_eq!(count, KINEMATIC_COLUMNS.len());
+        assert_eq!(rows, 721);
+        assert_eq!(flat.len(), 2 + count + count * rows);
+
+        let columns = analysis.as_columns();
+        for (c, name) in KINEMATIC_COLUMNS.iter().enumerate() {
+            let offset = flat[2 + c] as usize;
+            assert_eq!(&flat[offset..offset + rows], columns[name], "column {}", name);
+        }
+        assert_eq!(columns["velocity"], analysis.velocity.as_slice());
+    }
+
+    #[test]
+    fn pairs_to_map_reports_odd_length_and_nulls() {
+        let items = |v: &[Option<&str>]| v.iter().map(|s| s.map(str::to_string)).collect::<Vec<_>>();
+
+        let map = pairs_to_map(&items(&[Some("rpm"), Some("3000"), Some("max_lift"), Some("10")])).unwrap();
+        assert_eq!(map.get("rpm").map(String::as_str), Some("3000"));
+
+        let err = pairs_to_map(&items(&[Some("rpm"), Some("3000"), Some("max_lift"), Some("10"), Some("a"), Some("1"), Some("b"), Some("2"), Some("c")]))
+            .unwrap_err();
+        assert_eq!(err.variant_name(), "JNIParameterArray");
+        let msg = err.to_string();
+        assert!(msg.contains("length 9 is odd"), "{}", msg);
+        assert!(msg.contains(r#"first keys: ["rpm", "max_lift", "a"]"#), "{}", msg);
+
+        let err = pairs_to_map(&items(&[Some("rpm"), None])).unwrap_err();
+        assert!(err.to_string().contains(r#"null value for key "rpm" at index 1"#), "{}", err);
+        let err = pairs_to_map(&items(&[Some("rpm"), Some("3000"), None, Some("10")])).unwrap_err();
+        assert!(err.to_string().contains("null key at index 2"), "{}", err);
+    }
+
+    #[test]
+    fn last_error_json_reports_variant_after_deserialization_failure() {
+        crate::error::clear_last_error();
+        assert!(crate::get_last_error().contains("No error information available"));
+
+        let err = validate_tables_json("{not json").unwrap_err();
+        crate::error::record_error(&err, file!(), line!(), "validate_tables_json");
+
+        let json: serde_json::Value = serde_json::from_str(&crate::get_last_error()).unwrap();
+        assert_eq!(json["error_type"], "Deserialization");
+        assert!(json["message"].as_str().unwrap().starts_with("Deserialization error"));
+    }
+
+    #[test]
+    fn pitch_curves_json_cartesian_matches_radii() {
+        let params = LitvinParameters { sampling_step_deg: 2.0, ..LitvinParameters::default() };
+        let tables = litvin::build_litvin_tables(&params).expect("build_litvin_tables failed");
+
+        // Default payload stays polar-only
+        let polar = pitch_curves_to_json(&tables.curves, false);
+        assert!(polar.get("xCam").is_none());
+
+        let json = pitch_curves_to_json(&tables.curves, true);
+        let arr = |k: &str| -> Vec<f64> {
+            json[k].as_array().unwrap().iter().map(|v| v.as_f64().unwrap()).collect()
+        };
+        for (x, y, r) in [("xCam", "yCam", "rCam"), ("xRing", "yRing", "rRing")] {
+            let (xs, ys, rs) = (arr(x), arr(y), arr(r));
+            assert_eq!(xs.len(), rs.len());
+            for i in 0..rs.len() {
+                assert!((xs[i].hypot(ys[i]) - rs[i]).abs() < 1e-9, "{} radius mismatch at {}", r, i);
+            }
+        }
+    }
+
+    #[test]
+    fn advance_alpha_wraps_past_360() {
+        let id: jlong = -1414;
+        LITVIN_ALPHA.lock().unwrap().remove(&id);
+        // 3000 rpm = 18000 deg/s
+        let a = advance_litvin_alpha(id, 0.015, 3000.0);
+        assert!((a - 270.0).abs() < 1e-9, "alpha {}", a);
+        let a = advance_litvin_alpha(id, 0.01, 3000.0);
+        assert!((a - 90.0).abs() < 1e-9, "alpha {} did not wrap", a);
+        LITVIN_ALPHA.lock().unwrap().remove(&id);
+    }
+
+    #[test]
+    fn reset_then_advance_starts_from_reset_alpha() {
+        let id: jlong = -1415;
+        advance_litvin_alpha(id, 0.005, 3000.0);
+        assert!((reset_litvin_alpha(id, -30.0) - 330.0).abs() < 1e-9);
+        // 60 rpm = 360 deg/s
+        let a = advance_litvin_alpha(id, 0.25, 60.0);
+        assert!((a - 60.0).abs() < 1e-9, "alpha {}", a);
+        LITVIN_ALPHA.lock().unwrap().remove(&id);
+    }
+
+    #[test]
+    fn interpolated_state_matches_table_samples() {
+        let params = LitvinParameters { sampling_step_deg: 2.0, ..LitvinParameters::default() };
+        let tables = litvin::build_litvin_tables(&params).expect("build_litvin_tables failed");
+        let p = &tables.planets[0];
+        let at = |alpha: f64, key: &str| interpolated_state_json(&tables, alpha)[key][0].as_f64().unwrap();
+
+        assert!((at(10.0, "pistonS") - p.piston_s[5]).abs() < 1e-9);
+        assert!((at(11.0, "pistonS") - 0.5 * (p.piston_s[5] + p.piston_s[6])).abs() < 1e-9);
+        assert!((at(370.0, "pistonS") - p.piston_s[5]).abs() < 1e-9);
+        let spin = at(359.0, "spinPsiDeg");
+        assert!((0.0..360.0).contains(&spin));
+    }
+
+    #[test]
+    fn interpolated_states_follow_request_order() {
+        let params = LitvinParameters { sampling_step_deg: 2.0, ..LitvinParameters::default() };
+        let tables = litvin::build_litvin_tables(&params).expect("build_litvin_tables failed");
+        let alphas = [90.0, 10.0, 355.5, 10.0];
+        let states = interpolated_states_json(&tables, &alphas);
+        let states = states.as_array().unwrap();
+        assert_eq!(states.len(), alphas.len());
+        for (state, &alpha) in states.iter().zip(alphas.iter()) {
+            assert_eq!(state, &interpolated_state_json(&tables, alpha));
+            assert_eq!(state["alphaDeg"].as_f64().unwrap(), alpha);
+        }
+    }
+
+    #[test]
+    fn writers_accept_empty_journal_arrays() {
+        let params = LitvinParameters { sampling_step_deg: 2.0, compute_journal_geometry: false, ..LitvinParameters::default() };
+        let tables = litvin::build_litvin_tables(&params).expect("build_litvin_tables failed");
+        let dir = std::env::temp_dir().join(format!("litvin_no_journal_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        write_tables_json(&dir.join("tables.json"), &tables).expect("tables JSON");
+        write_boundary_json(&dir.join("boundary.json"), &tables).expect("boundary JSON");
+        write_state_json(&dir.join("state.json"), 30.0, &tables).expect("state JSON");
+        let state = interpolated_state_json(&tables, 31.0);
+        assert!(state["journalX"].as_array().unwrap().is_empty());
+        assert_eq!(state["pistonS"].as_array().unwrap().len(), tables.planets.len());
+        let _ = std::fs::remove_dir_all(dir);
+    }
+
+    #[test]
+    fn tables_json_schema_validation() {
+        let params = LitvinParameters { sampling_step_deg: 2.0, ..LitvinParameters::default() };
+        let tables = litvin::build_litvin_tables(&params).expect("build_litvin_tables failed");
+        let json = tables_to_json(&tables);
+        validate_tables_json(&json.to_string()).expect("produced tables JSON should validate");
+
+        let mut truncated = json.clone();
+        truncated["planets"][1]["pistonS"].as_array_mut().unwrap().pop();
+        match validate_tables_json(&truncated.to_string()) {
+            Err(FEAError::Serialization(msg)) => assert!(msg.contains("planets[1].pistonS"), "unexpected message: {}", msg),
+            other => panic!("Expected Serialization error, got {:?}", other),
+        }
+
+        let mut missing = json.clone();
+        missing.as_object_mut().unwrap().remove("diagnostics");
+        assert!(validate_tables_json(&missing.to_string()).is_err());
+        assert!(matches!(validate_tables_json("{"), Err(FEAError::Deserialization(_))));
+    }
+
+    #[test]
+    fn output_decimals_round_arrays_within_tolerance() {
+        let params = LitvinParameters { sampling_step_deg: 2.0, ..LitvinParameters::default() };
+        let tables = litvin::build_litvin_tables(&params).expect("build_litvin_tables failed");
+
+        let full = pitch_curves_to_json(&tables.curves, true);
+        let mut rounded = full.clone();
+        round_json_arrays(&mut rounded, Some(4));
+        let full_text = serde_json::to_string(&full).unwrap();
+        let rounded_text = serde_json::to_string(&rounded).unwrap();
+        assert!(rounded_text.len() < full_text.len());
+
+        let parsed: serde_json::Value = serde_json::from_str(&rounded_text).unwrap();
+        for (key, values) in full.as_object().unwrap() {
+            let orig = values.as_array().unwrap();
+            let back = parsed[key].as_array().unwrap();
+            assert_eq!(orig.len(), back.len());
+            for (a, b) in orig.iter().zip(back) {
+                assert!((a.as_f64().unwrap() - b.as_f64().unwrap()).abs() <= 1e-4, "{} not within 1e-4", key);
+            }
+        }
+
+        // Default keeps full precision
+        let mut untouched = full.clone();
+        round_json_arrays(&mut untouched, None);
+        assert_eq!(untouched, full);
+    }
+
+    #[test]
+    fn diagnostic_scalar_keys_match_json() {
+        let params = LitvinParameters { sampling_step_deg: 2.0, ..LitvinParameters::default() };
+        let tables = litvin::build_litvin_tables(&params).expect("build_litvin_tables failed");
+        let json = diagnostics_to_json(&tables.diagnostics);
+        for key in DIAGNOSTIC_SCALAR_KEYS {
+            let value = diagnostic_scalar(&tables.diagnostics, key).unwrap_or_else(|| panic!("{} not mapped", key));
+            assert_eq!(Some(value), json[*key].as_f64(), "{} does not match the diagnostics JSON", key);
+        }
+        assert_eq!(diagnostic_scalar(&tables.diagnostics, "jerkMax"), Some(tables.diagnostics.jerk_max));
+        assert_eq!(diagnostic_scalar(&tables.diagnostics, "arcResidualMax"), Some(tables.diagnostics.arc_length_residual_max));
+        assert_eq!(diagnostic_scalar(&tables.diagnostics, "notes"), None);
+        assert_eq!(diagnostic_scalar(&tables.diagnostics, "jerk_max"), None);
+    }
+
+    #[test]
+    fn diagnostics_json_units_cover_all_scalar_metrics() {
+        let params = LitvinParameters { sampling_step_deg: 2.0, ..LitvinParameters::default() };
+        let tables = litvin::build_litvin_tables(&params).expect("build_litvin_tables failed");
+        let json = diagnostics_to_json(&tables.diagnostics);
+
+        let units = json["units"].as_object().expect("units block missing");
+        for (key, value) in json.as_object().unwrap() {
+            if value.is_number() {
+                assert!(units.contains_key(key), "no unit declared for {}", key);
+            }
+        }
+        assert_eq!(units["accelMax"], "mm/s^2");
+        assert_eq!(units["jerkMax"], "mm/s^3");
+
+        // Values are unchanged by the units block
+        assert_eq!(json["accelMax"].as_f64().unwrap(), tables.diagnostics.accel_max);
+    }
+}
@@ -18,13 +18,49 @@
 //! - Native motion law implementation for maximum performance
 //! - Mathematical equivalence with Python design layer
 //! - Memory-efficient data structures for large simulations
-//! - Parallel computation support via rayon
+//! - Parallel computation support via rayon (`parallel` feature, on by default)
 //! - Real-time boundary condition calculation
 
+// The diagnostics `serde_json::json!` literal in jni.rs outgrows the default limit of 128
+#![recursion_limit = "256"]
+
 // Import crates
 #[macro_use]
 extern crate lazy_static;
 
+/// `par_iter()` with the `parallel` feature, a serial `iter()` without it
+macro_rules! maybe_par_iter {
+    ($e:expr) => {{
+        #[cfg(feature = "parallel")]
+        let iter = rayon::iter::IntoParallelRefIterator::par_iter($e);
+        #[cfg(not(feature = "parallel"))]
+        let iter = $e.iter();
+        iter
+    }};
+}
+
+/// `into_par_iter()` with the `parallel` feature, a serial `into_iter()` without it
+macro_rules! maybe_into_par_iter {
+    ($e:expr) => {{
+        #[cfg(feature = "parallel")]
+        let iter = rayon::iter::IntoParallelIterator::into_par_iter($e);
+        #[cfg(not(feature = "parallel"))]
+        let iter = IntoIterator::into_iter($e);
+        iter
+    }};
+}
+
+/// `par_chunks(size)` with the `parallel` feature, a serial `chunks(size)` without it
+macro_rules! maybe_par_chunks {
+    ($e:expr, $size:expr) => {{
+        #[cfg(feature = "parallel")]
+        let iter = rayon::slice::ParallelSlice::par_chunks($e, $size);
+        #[cfg(not(feature = "parallel"))]
+        let iter = $e.chunks($size);
+        iter
+    }};
+}
+
 // Export modules
 pub mod motion_law;
 pub mod error;
@@ -33,25 +69,149 @@ pub mod litvin;
 pub mod jni;
 
 // Re-export types
-pub use motion_law::{MotionLaw, MotionParameters, KinematicAnalysis};
+pub use motion_law::{MotionLaw, MotionLawSnapshot, MotionParameters, PartialMotionParameters, MotionSample, KinematicAnalysis, KinematicArrays, LimitUtilization, PrecomputedGrid, AccelSignConvention, ModifiedSineVariant, DiffStencil};
 pub use error::{FEAError, FEAResult, ErrorReport};
 pub use logging::{LogLevel, LogRecord, init_default_logger, init_file_logger, init_json_file_logger, init_memory_logger, get_last_logs, get_all_logs, clear_logs};
 
 /// Version information
 pub const VERSION: &str = env!("CARGO_PKG_VERSION");
 
+lazy_static! {
+    /// Field names of `MotionParameters`, taken from the serialized default so they always follow the struct
+    static ref MOTION_PARAMETER_KEYS: Vec<String> = match serde_json::to_value(MotionParameters::default()) {
+        Ok(serde_json::Value::Object(map)) => map.keys().cloned().collect(),
+        _ => Vec::new(),
+    };
+}
+
+/// Millimetres per inch
+pub const MM_PER_INCH: f64 = 25.4;
+
 /// Load motion parameters from a TOML file
+///
+/// An optional top-level `units` key selects the length unit of the file: `"mm"`
+/// (default) or `"in"`. Inch values are converted to mm on load, including the
+/// length part of the velocity, acceleration and jerk limits.
+///
+/// Unknown and duplicate keys are rejected with the key named in the error; use
+/// [`load_motion_parameters_from_toml_lenient`] to ignore unknown keys instead.
 pub fn load_motion_parameters_from_toml(toml_str: &str) -> FEAResult<MotionParameters> {
-    toml::from_str(toml_str)
-        .map_err(|e| FEAError::Deserialization(format!("Failed to parse TOML: {}", e)))
+    motion_parameters_from_toml(toml_str, true)
+}
+
+/// Load motion parameters from a TOML file, ignoring keys that are not parameter fields
+///
+/// For configs written by newer versions; otherwise identical to
+/// [`load_motion_parameters_from_toml`].
+pub fn load_motion_parameters_from_toml_lenient(toml_str: &str) -> FEAResult<MotionParameters> {
+    motion_parameters_from_toml(toml_str, false)
+}
+
+fn motion_parameters_from_toml(toml_str: &str, strict: bool) -> FEAResult<MotionParameters> {
+    let mut table: toml::value::Table = toml::from_str(toml_str)
+        .map_err(|e| FEAError::Deserialization(format!("Failed to parse TOML: {}", e)))?;
+    let length_scale = match table.remove("units") {
+        None => 1.0,
+        Some(toml::Value::String(u)) => match u.trim().to_lowercase().as_str() {
+            "mm" => 1.0,
+            "in" => MM_PER_INCH,
+            other => return Err(FEAError::Deserialization(format!("Unrecognized units '{}' (expected \"mm\" or \"in\")", other))),
+        },
+        Some(other) => return Err(FEAError::Deserialization(format!("units must be a string, got {}", other))),
+    };
+    if !strict {
+        table.retain(|key, _| MOTION_PARAMETER_KEYS.iter().any(|k| k == key));
+    }
+
+    let mut params: MotionParameters = toml::Value::Table(table)
+        .try_into()
+        .map_err(|e| FEAError::Deserialization(format!("Failed to parse TOML: {}", e)))?;
+    params.base_circle_radius *= length_scale;
+    params.max_lift *= length_scale;
+    params.velocity_limit *= length_scale;
+    params.acceleration_limit *= length_scale;
+    params.jerk_limit *= length_scale;
+    Ok(params)
 }
 
 /// Load motion parameters from a JSON file
+///
+/// Unknown and duplicate keys are rejected with the key named in the error.
 pub fn load_motion_parameters_from_json(json_str: &str) -> FEAResult<MotionParameters> {
     serde_json::from_str(json_str)
         .map_err(|e| FEAError::Deserialization(format!("Failed to parse JSON: {}", e)))
 }
 
+/// Load motion parameters from a JSON file, ignoring keys that are not parameter fields
+///
+/// Duplicate keys are not detected here; the last occurrence wins.
+pub fn load_motion_parameters_from_json_lenient(json_str: &str) -> FEAResult<MotionParameters> {
+    let mut value: serde_json::Value = serde_json::from_str(json_str)
+        .map_err(|e| FEAError::Deserialization(format!("Failed to parse JSON: {}", e)))?;
+    if let Some(map) = value.as_object_mut() {
+        map.retain(|key, _| MOTION_PARAMETER_KEYS.contains(key));
+    }
+    serde_json::from_value(value)
+        .map_err(|e| FEAError::Deserialization(format!("Failed to parse JSON: {}", e)))
+}
+
+/// Load a batch of motion parameter sets from a JSON array
+///
+/// Every set is validated; the error for the first invalid set names its index.
+pub fn load_motion_parameters_batch_from_json(json_str: &str) -> FEAResult<Vec<MotionParameters>> {
+    let sets: Vec<MotionParameters> = serde_json::from_str(json_str)
+        .map_err(|e| FEAError::Deserialization(format!("Failed to parse JSON batch: {}", e)))?;
+    for (index, params) in sets.iter().enumerate() {
+        params.validate().map_err(|e| {
+            FEAError::ParameterValidation(format!("Parameter set at index {} is invalid: {}", index, e))
+        })?;
+    }
+    Ok(sets)
+}
+
+/// Legacy motion parameter keys and the current keys they were renamed to
+///
+/// | legacy key         | current key          |
+/// |--------------------|----------------------|
+/// | `base_radius`      | `base_circle_radius` |
+/// | `lift`             | `max_lift`           |
+/// | `rise_angle`       | `rise_duration`      |
+/// | `dwell_angle`      | `dwell_duration`     |
+/// | `fall_angle`       | `fall_duration`      |
+/// | `engine_rpm`       | `rpm`                |
+pub const LEGACY_MOTION_PARAMETER_KEYS: &[(&str, &str)] = &[
+    ("base_radius", "base_circle_radius"),
+    ("lift", "max_lift"),
+    ("rise_angle", "rise_duration"),
+    ("dwell_angle", "dwell_duration"),
+    ("fall_angle", "fall_duration"),
+    ("engine_rpm", "rpm"),
+];
+
+/// Rewrite legacy keys in a motion parameter TOML config to their current names
+///
+/// Uses the mapping in [`LEGACY_MOTION_PARAMETER_KEYS`]; all other keys, including
+/// `units`, are kept as they are. A config that sets both a legacy key and its
+/// replacement is rejected rather than silently picking one. Comments and key
+/// order are not preserved.
+pub fn migrate_legacy_config(toml_str: &str) -> FEAResult<String> {
+    let mut table: toml::value::Table = toml::from_str(toml_str)
+        .map_err(|e| FEAError::Deserialization(format!("Failed to parse TOML: {}", e)))?;
+    for (legacy, current) in LEGACY_MOTION_PARAMETER_KEYS {
+        if let Some(value) = table.remove(*legacy) {
+            if table.contains_key(*current) {
+                return Err(FEAError::Deserialization(format!(
+                    "Config sets both legacy key `{}` and its replacement `{}`",
+                    legacy, current
+                )));
+            }
+            table.insert(current.to_string(), value);
+        }
+    }
+    toml::to_string(&table)
+        .map_err(|e| FEAError::Serialization(format!("Failed to serialize to TOML: {}", e)))
+}
+
 /// Create a new motion law from parameters
 pub fn create_motion_law(params: MotionParameters) -> FEAResult<MotionLaw> {
     MotionLaw::new(params)
@@ -71,10 +231,8 @@ pub fn export_motion_parameters_to_json(params: &MotionParameters) -> FEAResult<
 
 /// Get the last error as a JSON string
 pub fn get_last_error() -> String {
-    // This is a placeholder for a more sophisticated error reporting system
-    // In a real implementation, we would maintain a thread-local error log
-    // and return the last error from it
-    "{\"error\": \"No error information available\"}".to_string()
+    error::last_error_json()
+        .unwrap_or_else(|| "{\"error\": \"No error information available\"}".to_string())
 }
 
 #[cfg(test)]
@@ -103,6 +261,38 @@ mod tests {
         assert_eq!(params.rpm, 3000.0);
     }
 
+    #[test]
+    fn test_load_motion_parameters_from_toml_units() {
+        let base = r#"
+            base_circle_radius = 1.0
+            max_lift = 0.4
+            cam_duration = 180.0
+            rise_duration = 90.0
+            dwell_duration = 45.0
+            fall_duration = 90.0
+            jerk_limit = 1000.0
+            acceleration_limit = 500.0
+            velocity_limit = 100.0
+            rpm = 3000.0
+        "#;
+
+        let inches = load_motion_parameters_from_toml(&format!("units = \"in\"\n{}", base)).unwrap();
+        assert_relative_eq!(inches.max_lift, 0.4 * 25.4, epsilon = 1e-12);
+        assert_relative_eq!(inches.base_circle_radius, 25.4, epsilon = 1e-12);
+        assert_relative_eq!(inches.velocity_limit, 100.0 * 25.4, epsilon = 1e-9);
+        // Angles and speed are not lengths
+        assert_eq!(inches.rise_duration, 90.0);
+        assert_eq!(inches.rpm, 3000.0);
+
+        let mm = load_motion_parameters_from_toml(&format!("units = \"mm\"\n{}", base)).unwrap();
+        assert_eq!(mm.max_lift, 0.4);
+
+        match load_motion_parameters_from_toml(&format!("units = \"furlong\"\n{}", base)) {
+            Err(FEAError::Deserialization(msg)) => assert!(msg.contains("furlong")),
+            other => panic!("Expected Deserialization error, got {:?}", other),
+        }
+    }
+
     #[test]
     fn test_load_motion_parameters_from_json() {
         let json_str = r#"
@@ -126,6 +316,105 @@ mod tests {
         assert_eq!(params.rpm, 3000.0);
     }
 
+    #[test]
+    fn test_load_rejects_unknown_and_duplicate_keys() {
+        let toml_base = export_motion_parameters_to_toml(&MotionParameters::default()).unwrap();
+        let json_base = export_motion_parameters_to_json(&MotionParameters::default()).unwrap();
+
+        // camelCase typo must not silently fall back to the default
+        match load_motion_parameters_from_toml(&format!("maxLift = 12.0\n{}", toml_base)) {
+            Err(FEAError::Deserialization(msg)) => assert!(msg.contains("`maxLift`"), "unexpected message: {}", msg),
+            other => panic!("Expected Deserialization error, got {:?}", other),
+        }
+        let json_typo = json_base.replacen('{', "{\n  \"maxLift\": 12.0,", 1);
+        match load_motion_parameters_from_json(&json_typo) {
+            Err(FEAError::Deserialization(msg)) => assert!(msg.contains("`maxLift`"), "unexpected message: {}", msg),
+            other => panic!("Expected Deserialization error, got {:?}", other),
+        }
+
+        // Duplicates name the offending key
+        match load_motion_parameters_from_toml(&format!("{}max_lift = 3.0\n", toml_base)) {
+            Err(FEAError::Deserialization(msg)) => assert!(msg.contains("max_lift"), "unexpected message: {}", msg),
+            other => panic!("Expected Deserialization error, got {:?}", other),
+        }
+        let json_dup = json_base.replacen('{', "{\n  \"max_lift\": 3.0,", 1);
+        match load_motion_parameters_from_json(&json_dup) {
+            Err(FEAError::Deserialization(msg)) => assert!(msg.contains("duplicate field `max_lift`"), "unexpected message: {}", msg),
+            other => panic!("Expected Deserialization error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_unknown_fields_strict_and_lenient() {
+        let toml_str = format!("rpms = 4000.0\n{}", export_motion_parameters_to_toml(&MotionParameters::default()).unwrap());
+        match load_motion_parameters_from_toml(&toml_str) {
+            Err(FEAError::Deserialization(msg)) => assert!(msg.contains("unknown field `rpms`"), "unexpected message: {}", msg),
+            other => panic!("Expected Deserialization error, got {:?}", other),
+        }
+        let lenient = load_motion_parameters_from_toml_lenient(&toml_str).unwrap();
+        assert_eq!(lenient, MotionParameters::default());
+
+        let json_str = export_motion_parameters_to_json(&MotionParameters::default()).unwrap()
+            .replacen('{', "{\n  \"rpms\": 4000.0,", 1);
+        match load_motion_parameters_from_json(&json_str) {
+            Err(FEAError::Deserialization(msg)) => assert!(msg.contains("unknown field `rpms`"), "unexpected message: {}", msg),
+            other => panic!("Expected Deserialization error, got {:?}", other),
+        }
+        assert_eq!(load_motion_parameters_from_json_lenient(&json_str).unwrap(), MotionParameters::default());
+    }
+
+    #[test]
+    fn test_migrate_legacy_config() {
+        let legacy = r#"
+            base_radius = 25.0
+            lift = 10.0
+            cam_duration = 180.0
+            rise_angle = 90.0
+            dwell_duration = 45.0
+            fall_duration = 90.0
+            jerk_limit = 1000.0
+            acceleration_limit = 500.0
+            velocity_limit = 100.0
+            rpm = 3000.0
+        "#;
+        assert!(load_motion_parameters_from_toml(legacy).is_err());
+        let migrated = migrate_legacy_config(legacy).unwrap();
+        let params = load_motion_parameters_from_toml(&migrated).unwrap();
+        assert_eq!(params, MotionParameters::default());
+
+        // Output-only names are not renames of the limit inputs
+        let untouched = migrate_legacy_config("max_jerk = 1000.0\ntotal_duration = 360.0\n").unwrap();
+        let table: toml::value::Table = toml::from_str(&untouched).unwrap();
+        assert!(table.contains_key("max_jerk") && table.contains_key("total_duration"));
+        assert!(!table.contains_key("jerk_limit") && !table.contains_key("cam_duration"));
+
+        let conflicting = "lift = 10.0\nmax_lift = 12.0\n";
+        match migrate_legacy_config(conflicting) {
+            Err(FEAError::Deserialization(msg)) => assert!(msg.contains("`lift`"), "unexpected message: {}", msg),
+            other => panic!("Expected Deserialization error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_load_motion_parameters_batch_from_json() {
+        let valid = export_motion_parameters_to_json(&MotionParameters::default()).unwrap();
+        let invalid = export_motion_parameters_to_json(&MotionParameters { rpm: 0.0, ..MotionParameters::default() }).unwrap();
+
+        let batch = load_motion_parameters_batch_from_json(&format!("[{}, {}]", valid, valid)).unwrap();
+        assert_eq!(batch.len(), 2);
+
+        let result = load_motion_parameters_batch_from_json(&format!("[{}, {}, {}]", valid, invalid, valid));
+        match result {
+            Err(FEAError::ParameterValidation(msg)) => {
+                assert!(msg.contains("index 1"), "unexpected message: {}", msg);
+                assert!(msg.contains("RPM must be positive"));
+            },
+            other => panic!("Expected ParameterValidation error, got {:?}", other),
+        }
+
+        assert!(matches!(load_motion_parameters_batch_from_json("{}"), Err(FEAError::Deserialization(_))));
+    }
+
     #[test]
     fn test_create_motion_law() {
         let params = MotionParameters::default();
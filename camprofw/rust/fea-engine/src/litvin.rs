@@ -5,10 +5,17 @@
 //! cam transmission system. It supports various motion profiles (Cycloidal, S5, S7)
 //! and performs arc-length conjugacy with residual control.
 
-use serde::Serialize;
+use crate::error::{FEAError, FEAResult};
+use crate::logging::{self, LogLevel};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
 use std::f64::consts::PI;
+use std::fs::File;
+use std::io::{BufReader, BufWriter, Read, Write};
+use std::path::Path;
 
-#[derive(Clone, Copy, Debug, Serialize)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub enum RampProfile {
     S5,
     S7,
@@ -17,6 +24,51 @@ pub enum RampProfile {
 
 impl Default for RampProfile { fn default() -> Self { RampProfile::S5 } }
 
+impl RampProfile {
+    /// All ramp profile variants, in declaration order.
+    pub const ALL: [RampProfile; 3] = [RampProfile::S5, RampProfile::S7, RampProfile::Cycloidal];
+
+    /// Position of the variant in [`RampProfile::ALL`].
+    pub const fn index(self) -> usize {
+        match self {
+            RampProfile::S5 => 0,
+            RampProfile::S7 => 1,
+            RampProfile::Cycloidal => 2,
+        }
+    }
+}
+
+/// Interpolation order used when sampling the pitch-curve tables.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum InterpOrder {
+    #[default]
+    Linear,
+    /// Catmull-Rom cubic through the four neighbouring samples
+    Cubic,
+}
+
+/// Which member of the cam/ring pair is the internal (enclosed) pitch curve.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum MeshType {
+    /// Ring encloses the cam: r_ring starts at C − r_cam and clearance is r_ring − r_cam
+    #[default]
+    ExternalCamInternalRing,
+    /// Cam encloses the ring: r_ring starts at r_cam − C and clearance is r_cam − r_ring
+    InternalCamExternalRing,
+}
+
+/// Catmull-Rom spline through p1 (w = 0) and p2 (w = 1), with p0/p3 as outer neighbours.
+fn catmull_rom(p0: f64, p1: f64, p2: f64, p3: f64, w: f64) -> f64 {
+    let w2 = w * w;
+    let w3 = w2 * w;
+    0.5 * (2.0 * p1 + (p2 - p0) * w + (2.0 * p0 - 5.0 * p1 + 4.0 * p2 - p3) * w2 + (3.0 * p1 - p0 - 3.0 * p2 + p3) * w3)
+}
+
+/// Derivative of [`catmull_rom`] with respect to w.
+fn catmull_rom_dw(p0: f64, p1: f64, p2: f64, p3: f64, w: f64) -> f64 {
+    0.5 * ((p2 - p0) + 2.0 * (2.0 * p0 - 5.0 * p1 + 4.0 * p2 - p3) * w + 3.0 * (3.0 * p1 - p0 - 3.0 * p2 + p3) * w * w)
+}
+
 /// Evaluation result for motion profiles, containing the normalized position (s),
 /// velocity (ds/dt), and acceleration (d²s/dt²) for t ∈ [0,1].
 #[derive(Clone, Copy, Debug)]
@@ -26,6 +78,11 @@ pub struct ProfileEval {
     pub d2s: f64,
 }
 
+lazy_static! {
+    /// `MotionProfiles::integral(profile, 1.0)` for each profile, in `RampProfile::ALL` order
+    static ref FULL_RAMP_INTEGRALS: [f64; 3] = RampProfile::ALL.map(|p| MotionProfiles::integral(p, 1.0));
+}
+
 /// Motion profile utilities for normalized ramp profiles.
 /// These functions shape velocity ramps from 0 -> V or V -> 0.
 pub struct MotionProfiles;
@@ -106,6 +163,11 @@ impl MotionProfiles {
         ProfileEval { s, ds, d2s }
     }
     
+    /// Integral of s(t) over the whole ramp, t ∈ [0,1]; cached per profile.
+    pub fn full_integral(profile: RampProfile) -> f64 {
+        FULL_RAMP_INTEGRALS[profile.index()]
+    }
+
     /// Integral of s(t) for fast analytic displacement integration.
     pub fn integral(profile: RampProfile, t: f64) -> f64 {
         let tt = t.max(0.0).min(1.0);  // Clamp to [0,1]
@@ -135,7 +197,7 @@ impl MotionProfiles {
     }
 }
 
-#[derive(Clone, Debug, Serialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct LitvinParameters {
     pub up_fraction: f64,
     pub dwell_tdc_deg: f64,
@@ -162,6 +224,54 @@ pub struct LitvinParameters {
     // Wave 2 optional controls (additive)
     pub arc_residual_tol_mm: f64,
     pub max_iter: i32,
+    /// Minimum manufacturable gear module (mm) used for the ring tooth-count check
+    pub min_module: f64,
+    /// Also export Cartesian (x, y) pitch curves alongside the polar radii
+    pub export_cartesian_curves: bool,
+    /// Interpolation used by the conjugacy inversion and residual evaluation
+    pub interp_order: InterpOrder,
+    /// Round exported JSON arrays to this many decimal places (None = full f64 precision)
+    pub output_decimals: Option<u32>,
+    /// Fill the planet journal x/y arrays; when false they are left empty and only the journal's
+    /// projection onto the slider axis is evaluated (piston motion only)
+    pub compute_journal_geometry: bool,
+    /// Cam turns in the negative direction: negates the angular velocity used for
+    /// time derivatives and sliding velocity, and the direction of planet spin
+    pub reverse_rotation: bool,
+    /// Minimum acceptable clearance (mm) for `Diagnostics::clearance_pass`
+    pub min_clearance_target: f64,
+    /// Sliding velocity limit (mm/s) for `Diagnostics::sliding_velocity_pass`; None = unchecked
+    pub max_sliding_velocity: Option<f64>,
+    /// Target mean piston speed (m/s); when set, overrides `rpm` using `rod_length` as the stroke
+    pub mean_piston_speed_mps: Option<f64>,
+    /// NVH amplitude limit (mm/s²) for `Diagnostics::nvh_limit_fraction`; None = unchecked
+    pub nvh_amp_limit: Option<f64>,
+    /// Build time budget (ms) for `Diagnostics::over_budget`; None = unchecked
+    pub build_time_budget_ms: Option<f64>,
+    /// Piston acceleration limit (mm/s²) for `Diagnostics::accel_pass`; None = unchecked
+    pub accel_limit: Option<f64>,
+    /// Motion-law jerk limit (mm/s³) for `Diagnostics::jerk_pass`; None = unchecked
+    pub jerk_limit: Option<f64>,
+    /// Cycles of piston acceleration tiled before the NVH transform; N cycles give bins every
+    /// 1/N engine order over the same order range. The tiled signal is exactly periodic, so the
+    /// bins between engine orders are zero: no new content, at N² times the transform cost
+    pub nvh_cycles: u32,
+    /// Profile of the ramp after TDC (0 → +v_up); None = `ramp_profile`
+    pub ramp_after_tdc_profile: Option<RampProfile>,
+    /// Profile of the ramp before BDC (+v_up → 0); None = `ramp_profile`
+    pub ramp_before_bdc_profile: Option<RampProfile>,
+    /// Profile of the ramp after BDC (0 → −v_dn); None = `ramp_profile`
+    pub ramp_after_bdc_profile: Option<RampProfile>,
+    /// Profile of the ramp before TDC (−v_dn → 0); None = `ramp_profile`
+    pub ramp_before_tdc_profile: Option<RampProfile>,
+    /// Keep a copy of `r_ring` from every conjugacy iteration in `Diagnostics::iterate_snapshots`
+    /// (debugging only: costs iter_count × n samples)
+    pub capture_iterates: bool,
+    /// Newton steps refining each linear φ(θ) inversion on the Catmull-Rom arc-length segment,
+    /// using the local `s_ring` slope (0 = linear only; cubic interpolation always refines)
+    pub phi_refine_steps: u32,
+    /// Cam/ring nesting; flips the ring-radius initialization and the clearance sign
+    pub mesh_type: MeshType,
 }
 
 impl Default for LitvinParameters {
@@ -191,6 +301,27 @@ impl Default for LitvinParameters {
             center_distance_scale: 1.0,
             arc_residual_tol_mm: 0.01,
             max_iter: 20,
+            min_module: 1.0,
+            export_cartesian_curves: false,
+            interp_order: InterpOrder::Linear,
+            output_decimals: None,
+            compute_journal_geometry: true,
+            reverse_rotation: false,
+            min_clearance_target: 0.0,
+            max_sliding_velocity: None,
+            mean_piston_speed_mps: None,
+            nvh_amp_limit: None,
+            build_time_budget_ms: None,
+            accel_limit: None,
+            jerk_limit: None,
+            nvh_cycles: 1,
+            ramp_after_tdc_profile: None,
+            ramp_before_bdc_profile: None,
+            ramp_after_bdc_profile: None,
+            ramp_before_tdc_profile: None,
+            capture_iterates: false,
+            phi_refine_steps: 0,
+            mesh_type: MeshType::ExternalCamInternalRing,
         }
     }
 }
@@ -206,11 +337,81 @@ impl LitvinParameters {
         if self.planet_count < 1 || self.planet_count > 2 {
             return Err("planet_count must be 1 or 2 in this minimal implementation".to_string());
         }
+        if self.nvh_cycles < 1 {
+            return Err("nvh_cycles must be at least 1".to_string());
+        }
+        if self.min_module <= 0.0 {
+            return Err("min_module must be positive".to_string());
+        }
+        if !(0.0..self.center_distance_bias).contains(&self.interference_buffer) {
+            return Err(format!(
+                "interference_buffer ({}) must be in [0, center_distance_bias = {})",
+                self.interference_buffer, self.center_distance_bias
+            ));
+        }
+        if let Some(speed) = self.mean_piston_speed_mps {
+            if speed <= 0.0 || self.rod_length <= 0.0 {
+                return Err("mean_piston_speed_mps requires a positive speed and rod_length".to_string());
+            }
+        }
+        // Mirror generate_motion_law's layout: TDC dwell starts at 0°, BDC dwell is centred on 180°
+        let half_bdc = self.dwell_bdc_deg.max(0.0) / 2.0;
+        let tdc_half = self.dwell_tdc_deg.max(0.0) + self.ramp_after_tdc_deg.max(0.0) + self.ramp_before_bdc_deg.max(0.0) + half_bdc;
+        if tdc_half > 180.0 {
+            return Err(format!(
+                "TDC-half segments (dwell_tdc + ramp_after_tdc + ramp_before_bdc + dwell_bdc/2 = {:.3}°) exceed 180°",
+                tdc_half
+            ));
+        }
+        let bdc_half = half_bdc + self.ramp_after_bdc_deg.max(0.0) + self.ramp_before_tdc_deg.max(0.0);
+        if bdc_half > 180.0 {
+            return Err(format!(
+                "BDC-half segments (dwell_bdc/2 + ramp_after_bdc + ramp_before_tdc = {:.3}°) exceed 180°",
+                bdc_half
+            ));
+        }
         Ok(())
     }
+
+    /// Stable 64-bit fingerprint of every parameter value (FNV-1a over the bincode encoding),
+    /// used to reject caches built from different parameters.
+    pub fn fingerprint(&self) -> u64 {
+        const FNV_OFFSET: u64 = 0xcbf2_9ce4_8422_2325;
+        const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+        bincode::serialize(self)
+            .unwrap_or_default()
+            .iter()
+            .fold(FNV_OFFSET, |hash, &b| (hash ^ b as u64).wrapping_mul(FNV_PRIME))
+    }
+
+    /// Profiles of the four ramps in cycle order (after TDC, before BDC, after BDC, before TDC),
+    /// with unset overrides falling back to `ramp_profile`.
+    pub fn ramp_profiles(&self) -> [RampProfile; 4] {
+        [
+            self.ramp_after_tdc_profile,
+            self.ramp_before_bdc_profile,
+            self.ramp_after_bdc_profile,
+            self.ramp_before_tdc_profile,
+        ]
+        .map(|p| p.unwrap_or(self.ramp_profile))
+    }
+
+    /// Crank speed used by the build: `rpm`, or the speed implied by `mean_piston_speed_mps`.
+    pub fn effective_rpm(&self) -> f64 {
+        match self.mean_piston_speed_mps {
+            Some(speed) => rpm_from_mean_piston_speed(self.rod_length, speed),
+            None => self.rpm,
+        }
+    }
 }
 
-#[derive(Clone, Debug)]
+/// Crank speed (rpm) giving a mean piston speed (m/s) over a stroke (mm).
+/// The piston covers two strokes per revolution: v̄ = 2 · stroke · rpm / 60.
+pub fn rpm_from_mean_piston_speed(stroke_mm: f64, mean_speed_mps: f64) -> f64 {
+    mean_speed_mps * 60.0 / (2.0 * stroke_mm / 1000.0)
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct PitchCurves {
     pub theta_deg: Vec<f64>,
     pub r_cam: Vec<f64>,
@@ -219,19 +420,64 @@ pub struct PitchCurves {
     pub s_cam: Vec<f64>,
     pub s_ring: Vec<f64>,
     pub phi_of_theta_deg: Vec<f64>,
+    pub r_ring_of_theta: Vec<f64>,  // r_ring sampled at φ(θ), on the θ grid
 }
 
+/// Cartesian form of the pitch curves.
+/// The cam curve is expressed in the cam frame at angle θ (`theta_deg`),
+/// the ring curve in the ring frame at angle φ (`phi_deg`); both are centred on their own axis.
 #[derive(Clone, Debug)]
+pub struct CartesianPitchCurves {
+    pub x_cam: Vec<f64>,
+    pub y_cam: Vec<f64>,
+    pub x_ring: Vec<f64>,
+    pub y_ring: Vec<f64>,
+}
+
+impl PitchCurves {
+    /// Convert the polar radii to Cartesian coordinates (cam vs θ, ring vs φ).
+    pub fn cartesian(&self) -> CartesianPitchCurves {
+        let to_xy = |r: &[f64], ang_deg: &[f64]| -> (Vec<f64>, Vec<f64>) {
+            r.iter()
+                .zip(ang_deg)
+                .map(|(&r, &a)| {
+                    let a = a * PI / 180.0;
+                    (r * a.cos(), r * a.sin())
+                })
+                .unzip()
+        };
+        let (x_cam, y_cam) = to_xy(&self.r_cam, &self.theta_deg);
+        let (x_ring, y_ring) = to_xy(&self.r_ring, &self.phi_deg);
+        CartesianPitchCurves { x_cam, y_cam, x_ring, y_ring }
+    }
+
+    /// Phase lag φ(θ) − θ (deg) of the ring behind/ahead of the cam at each θ sample,
+    /// normalized to (−180, 180].
+    pub fn phase_lag_deg(&self) -> Vec<f64> {
+        self.phi_of_theta_deg
+            .iter()
+            .zip(&self.theta_deg)
+            .map(|(&phi, &theta)| {
+                let lag = (phi - theta).rem_euclid(360.0);
+                if lag > 180.0 { lag - 360.0 } else { lag }
+            })
+            .collect()
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct PlanetState {
     pub center_x: Vec<f64>,
     pub center_y: Vec<f64>,
-    pub spin_psi_deg: Vec<f64>,
+    pub spin_psi_deg: Vec<f64>,            // wrapped to [0,360) for display
+    pub spin_psi_deg_unwrapped: Vec<f64>,  // continuous accumulation, safe to difference
     pub journal_x: Vec<f64>,
     pub journal_y: Vec<f64>,
     pub piston_s: Vec<f64>,
 }
 
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct Diagnostics {
     // Arc-length conjugacy metrics
     pub arc_length_residual_max: f64,
@@ -239,10 +485,12 @@ pub struct Diagnostics {
     pub iter_count: i32,
     pub used_max_iter: bool,
     pub regularization_applied: bool,
+    pub iterate_snapshots: Vec<Vec<f64>>, // r_ring evaluated by each iteration; empty unless capture_iterates
     
     // Clearance metrics
     pub clearance_min: f64,
     pub clearance_violations: Vec<ClearanceViolation>,
+    pub clearance_pass: bool,     // clearance_min >= min_clearance_target
     pub envelope_clearance_min: f64,
     pub envelope_violations: Vec<ClearanceViolation>,
     
@@ -250,41 +498,199 @@ pub struct Diagnostics {
     pub tooth_thickness_min: f64,
     pub undercut_flag: bool,
     pub curvature_radius_min: f64,
+    pub feasible_tooth_count: i32,  // floor(ring pitch length / (π · min_module))
+    pub tooth_count_infeasible: bool, // feasible_tooth_count < MIN_RING_TEETH
+    pub cam_mean_radius: f64,     // Mean of r_cam, for drawing the cam reference circle
+    pub ring_mean_radius: f64,    // Mean of r_ring, for drawing the ring reference circle
     
     // Motion metrics
     pub tracking_rms: f64,        // RMS error between target x(θ) and reconstructed piston path
     pub accel_max: f64,           // Maximum acceleration (mm/s²)
     pub jerk_max: f64,            // Maximum jerk (mm/s³)
+    pub accel_pass: bool,         // accel_max <= accel_limit (true when unset)
+    pub jerk_pass: bool,          // jerk_max <= jerk_limit (true when unset)
+    pub achieved_stroke: f64,     // max − min piston_s of planet 0 (mm), compare to rod_length
+    pub stroke_error: f64,        // achieved_stroke − rod_length (mm); positive = overshoot, independent of shape error
+    pub degenerate: bool,         // v_up and v_dn both ≈ 0: no stroke, tracking metrics are meaningless
+    pub motion_law_accel_mismatch: f64, // max |a_analytic − d²x/dθ²| / max |a_analytic| of the motion law
+    pub points_per_shortest_ramp: Option<f64>, // shortest non-zero ramp / sampling_step_deg; None without ramps
+    pub spin_closure_error: f64,  // |closed ψ accumulation − Simpson ∮(r_ring/r_cam − 1)dα| over one cycle (deg)
     
     // Sliding velocity metrics
     pub sliding_vel_mean: f64,    // Mean sliding velocity
     pub sliding_vel_max: f64,     // Maximum sliding velocity
+    pub sliding_velocity_pass: bool,                // sliding_vel_max <= max_sliding_velocity (true when unset)
+    pub sliding_velocity_exceeded_alpha_deg: Option<f64>, // First α where the limit is exceeded
     
     // NVH metrics
     pub nvh_peaks: Vec<NvhPeak>,  // Top frequency peaks (Hz, magnitude) from piston acceleration FFT
+    pub dominant_nvh_order: i32,  // Engine order (1-based) nearest the largest NVH peak
+    pub dominant_nvh_amp: f64,    // Amplitude of the largest NVH peak
+    pub nvh_limit_fraction: Option<f64>, // dominant_nvh_amp / nvh_amp_limit, when a limit is set
+    pub mesh_frequency_hz: f64,   // Planet mesh frequency: planet_count · rpm / 60
+    pub nvh_at_mesh_frequency: bool, // Largest NVH peak within MESH_FREQUENCY_BAND of a mesh harmonic
     
     // Recommendations and performance
     pub suggested_center_distance_inflation: f64,
     pub build_ms: f64,            // Build time in milliseconds
+    pub over_budget: bool,        // build_ms > build_time_budget_ms (false when unset)
+    pub timing: TimingBreakdown,  // build_ms split by phase
     
     // Notes for debugging/additional info
     pub notes: Vec<String>,
 }
 
-#[derive(Clone, Debug)]
+/// One-line summary of the pass/fail-relevant metrics, for logs and test output.
+impl std::fmt::Display for Diagnostics {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "arc_residual_max={:.3e} mm clearance_min={:.4} mm (pass={}) jerk_max={:.3e} mm/s^3 \
+             tracking_rms={:.3e} mm iter_count={}{}",
+            self.arc_length_residual_max,
+            self.clearance_min,
+            self.clearance_pass,
+            self.jerk_max,
+            self.tracking_rms,
+            self.iter_count,
+            if self.used_max_iter { " (max_iter reached)" } else { "" }
+        )
+    }
+}
+
+/// Units of the diagnostics metrics, keyed by their JSON name.
+/// Nested entries use `parent.child` keys (e.g. `nvhPeaks.freqHz`).
+pub const DIAGNOSTIC_UNITS: &[(&str, &str)] = &[
+    ("arcLengthResidualMax", "mm"),
+    ("arcLengthResidualRms", "mm"),
+    ("iterCount", "1"),
+    ("clearanceMin", "mm"),
+    ("clearanceViolations.alphaStartDeg", "deg"),
+    ("clearanceViolations.alphaEndDeg", "deg"),
+    ("clearanceViolations.minClearance", "mm"),
+    ("clearanceViolations.severity", "mm*deg"),
+    ("clearanceViolations.timeStartS", "s"),
+    ("clearanceViolations.timeEndS", "s"),
+    ("envelopeClearanceMin", "mm"),
+    ("envelopeViolations.alphaStartDeg", "deg"),
+    ("envelopeViolations.alphaEndDeg", "deg"),
+    ("envelopeViolations.minClearance", "mm"),
+    ("envelopeViolations.severity", "mm*deg"),
+    ("envelopeViolations.timeStartS", "s"),
+    ("envelopeViolations.timeEndS", "s"),
+    ("toothThicknessMin", "mm"),
+    ("curvatureRadiusMin", "mm"),
+    ("feasibleToothCount", "1"),
+    ("camMeanRadius", "mm"),
+    ("ringMeanRadius", "mm"),
+    ("trackingRms", "mm"),
+    ("accelMax", "mm/s^2"),
+    ("jerkMax", "mm/s^3"),
+    ("achievedStroke", "mm"),
+    ("strokeError", "mm"),
+    ("motionLawAccelMismatch", "1"),
+    ("pointsPerShortestRamp", "1"),
+    ("spinClosureError", "deg"),
+    ("slidingVelMean", "mm/s"),
+    ("slidingVelMax", "mm/s"),
+    ("slidingVelocityExceededAlphaDeg", "deg"),
+    ("nvhPeaks.freqHz", "Hz"),
+    ("nvhPeaks.amp", "mm/s^2"),
+    ("dominantNvhOrder", "1"),
+    ("dominantNvhAmp", "mm/s^2"),
+    ("nvhLimitFraction", "1"),
+    ("meshFrequencyHz", "Hz"),
+    ("suggestedCenterDistanceInflation", "mm"),
+    ("buildMs", "ms"),
+    ("timing.motionLawMs", "ms"),
+    ("timing.conjugacyMs", "ms"),
+    ("timing.kinematicsMs", "ms"),
+    ("timing.clearanceMs", "ms"),
+    ("timing.nvhMs", "ms"),
+    ("timing.otherMs", "ms"),
+];
+
+/// Relative half-width of the band around k · `mesh_frequency_hz` in which the largest NVH peak
+/// sets `Diagnostics::nvh_at_mesh_frequency`.
+pub const MESH_FREQUENCY_BAND: f64 = 0.05;
+
+/// `Diagnostics::points_per_shortest_ramp` below which a note asks for a finer `sampling_step_deg`.
+pub const MIN_POINTS_PER_RAMP: f64 = 8.0;
+
+/// `Diagnostics::spin_closure_error` (deg) above which a note reports spin drift over the cycle.
+pub const SPIN_CLOSURE_TOLERANCE_DEG: f64 = 0.01;
+
+/// Minimum tooth count for full-depth 20° involute teeth without undercut (2 / sin²20° ≈ 17.1).
+pub const MIN_RING_TEETH: i32 = 17;
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct ClearanceViolation {
     pub alpha_start_deg: f64,
     pub alpha_end_deg: f64,
-    pub min_clearance: f64,
+    pub min_clearance: f64,  // deepest (most negative) clearance within this violation
+    pub severity: f64,       // depth × angular extent (mm·deg); violations are sorted by it, descending
+    pub time_start_s: f64,   // time within the cycle at alpha_start_deg (s)
+    pub time_end_s: f64,     // time within the cycle at alpha_end_deg (s)
 }
 
-#[derive(Clone, Debug)]
+/// Scan a clearance series for contiguous negative runs.
+/// Returns the overall minimum clearance and the violations ordered by descending severity.
+/// `deg_per_sec` is dα/dt (negative for reverse rotation) and converts angles to cycle times.
+fn collect_violations(alpha_deg: &[f64], clearance: &[f64], deg_per_sec: f64) -> (f64, Vec<ClearanceViolation>) {
+    let n = clearance.len();
+    let step = if n > 1 { alpha_deg[1] - alpha_deg[0] } else { 0.0 };
+    let mut overall_min = f64::INFINITY;
+    let mut violations: Vec<ClearanceViolation> = Vec::new();
+    let mut run: Option<(usize, f64)> = None; // (start index, min clearance in run)
+    let period_s = 360.0 / deg_per_sec.abs();
+    let time_at = |alpha: f64| (alpha / deg_per_sec).rem_euclid(period_s);
+    let close = |start: usize, end: usize, depth: f64, out: &mut Vec<ClearanceViolation>| {
+        let extent = (alpha_deg[end] - alpha_deg[start]).max(step);
+        out.push(ClearanceViolation {
+            alpha_start_deg: alpha_deg[start],
+            alpha_end_deg: alpha_deg[end],
+            min_clearance: depth,
+            severity: -depth * extent,
+            time_start_s: time_at(alpha_deg[start]),
+            time_end_s: time_at(alpha_deg[end]),
+        });
+    };
+    for (i, &g) in clearance.iter().enumerate() {
+        if g < overall_min { overall_min = g; }
+        if g < 0.0 {
+            run = Some(match run { Some((start, m)) => (start, m.min(g)), None => (i, g) });
+        } else if let Some((start, m)) = run.take() {
+            close(start, i, m, &mut violations);
+        }
+    }
+    if let Some((start, m)) = run {
+        close(start, n - 1, m, &mut violations);
+    }
+    violations.sort_by(|a, b| b.severity.total_cmp(&a.severity));
+    (overall_min, violations)
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct NvhPeak {
     pub freq_hz: f64,
     pub amp: f64,
 }
 
-#[derive(Clone, Debug)]
+/// Per-phase wall time (ms) of a build; the phases run back to back and sum to `build_ms`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TimingBreakdown {
+    pub motion_law_ms: f64,  // Motion-law generation
+    pub conjugacy_ms: f64,   // Pitch curves and the arc-length conjugacy loop
+    pub kinematics_ms: f64,  // Planet, journal and piston tracks (step 4)
+    pub clearance_ms: f64,   // Clearance checks and manufacturability proxies (step 5)
+    pub nvh_ms: f64,         // Piston acceleration/jerk and the NVH transform
+    pub other_ms: f64,       // Tracking, sliding velocity and notes
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct LitvinTables {
     pub params: LitvinParameters,
     pub curves: PitchCurves,
@@ -293,11 +699,257 @@ pub struct LitvinTables {
     pub diagnostics: Diagnostics,
 }
 
+/// Leading bytes of a [`LitvinTables::save_cache`] file
+const LITVIN_CACHE_MAGIC: [u8; 4] = *b"LTVC";
+/// Cache layout version; bump whenever a serialized table or parameter field changes
+pub const LITVIN_CACHE_VERSION: u32 = 13;
+
+impl LitvinTables {
+    /// Write the tables to `path` as a bincode cache: magic, [`LITVIN_CACHE_VERSION`],
+    /// the parameter fingerprint, then the tables.
+    pub fn save_cache<P: AsRef<Path>>(&self, path: P) -> FEAResult<()> {
+        let mut writer = BufWriter::new(File::create(path)?);
+        writer.write_all(&LITVIN_CACHE_MAGIC)?;
+        let header = (LITVIN_CACHE_VERSION, self.params.fingerprint());
+        bincode::serialize_into(&mut writer, &header).map_err(|e| FEAError::Serialization(e.to_string()))?;
+        bincode::serialize_into(&mut writer, self).map_err(|e| FEAError::Serialization(e.to_string()))?;
+        writer.flush()?;
+        Ok(())
+    }
+
+    /// Load tables written by [`save_cache`](Self::save_cache) for `params`.
+    ///
+    /// Fails with a `Deserialization` error when the file is not a cache, was written by
+    /// another cache version, or was built from different parameters (a stale cache).
+    pub fn load_cache<P: AsRef<Path>>(path: P, params: &LitvinParameters) -> FEAResult<LitvinTables> {
+        let mut reader = BufReader::new(File::open(path)?);
+        let mut magic = [0u8; 4];
+        reader.read_exact(&mut magic)?;
+        if magic != LITVIN_CACHE_MAGIC {
+            return Err(FEAError::Deserialization("not a Litvin tables cache".to_string()));
+        }
+        let (version, fingerprint): (u32, u64) =
+            bincode::deserialize_from(&mut reader).map_err(|e| FEAError::Deserialization(e.to_string()))?;
+        if version != LITVIN_CACHE_VERSION {
+            return Err(FEAError::Deserialization(format!(
+                "cache version {} does not match {}",
+                version, LITVIN_CACHE_VERSION
+            )));
+        }
+        // Tables store the rpm resolved from mean_piston_speed_mps; compare against the same
+        let expected = LitvinParameters { rpm: params.effective_rpm(), ..params.clone() };
+        if fingerprint != expected.fingerprint() {
+            return Err(FEAError::Deserialization("stale cache: built from different parameters".to_string()));
+        }
+        bincode::deserialize_from(&mut reader).map_err(|e| FEAError::Deserialization(e.to_string()))
+    }
+
+    /// Full [`Diagnostics`] as a camelCase JSON string, without touching the filesystem.
+    pub fn diagnostics_json(&self) -> String {
+        serde_json::to_string(&self.diagnostics).unwrap_or_else(|_| "{}".to_string())
+    }
+
+    /// Swept envelope of all planets' journal paths: the convex hull of the combined
+    /// journal points, counter-clockwise in the carrier frame. Empty when
+    /// `compute_journal_geometry` is off.
+    pub fn journal_envelope(&self) -> Vec<(f64, f64)> {
+        let pts: Vec<(f64, f64)> = self
+            .planets
+            .iter()
+            .flat_map(|p| p.journal_x.iter().copied().zip(p.journal_y.iter().copied()))
+            .collect();
+        convex_hull(pts)
+    }
+
+    /// Achieved piston stroke (max − min `piston_s`) of the given planet, in mm.
+    /// Panics if `planet_index` is out of range.
+    pub fn achieved_stroke(&self, planet_index: usize) -> f64 {
+        piston_stroke(&self.planets[planet_index].piston_s)
+    }
+
+    /// RMS tracking error (mm) of planet 0 against the target motion law, over the α window
+    /// from `alpha_start` to `alpha_end` (deg, inclusive).
+    ///
+    /// The window is read modulo 360°, so a start after the end wraps through 0° (e.g. 350 → 10);
+    /// a window spanning 360° or more covers the whole cycle and matches
+    /// `Diagnostics::tracking_rms`. The target is regenerated from the stored parameters.
+    /// Returns 0 when no α sample falls in the window.
+    pub fn tracking_rms_over(&self, alpha_start: f64, alpha_end: f64) -> f64 {
+        let Ok((_, x_mm, _, _)) = generate_motion_law(&self.params) else { return 0.0 };
+        let full = alpha_end - alpha_start >= 360.0;
+        let (start, end) = (alpha_start.rem_euclid(360.0), alpha_end.rem_euclid(360.0));
+        let in_window = |a: f64| full || if start <= end { a >= start && a <= end } else { a >= start || a <= end };
+        let (sum, count) = self.alpha_deg
+            .iter()
+            .zip(x_mm.iter().zip(&self.planets[0].piston_s))
+            .filter(|(&a, _)| in_window(a))
+            .fold((0.0, 0usize), |(sum, count), (_, (target, actual))| (sum + (target - actual).powi(2), count + 1));
+        if count == 0 { 0.0 } else { (sum / count as f64).sqrt() }
+    }
+
+    /// Piston displacement (mm), velocity (mm/s) and acceleration (mm/s²) of `planet` at time
+    /// `t` seconds after α = 0, for transient solvers whose sub-steps do not fall on the α grid.
+    ///
+    /// Time maps to α through the stored rpm (6·rpm deg/s, negated for reverse rotation) and
+    /// wraps periodically. Velocity and acceleration are periodic central differences of
+    /// `piston_s` in time; all three channels are interpolated with the tables' `interp_order`.
+    /// Panics if `planet` is out of range.
+    pub fn piston_at_time(&self, planet: usize, t: f64) -> (f64, f64, f64) {
+        let s = &self.planets[planet].piston_s;
+        let n = s.len();
+        let step_deg = self.params.sampling_step_deg;
+        let direction = if self.params.reverse_rotation { -1.0 } else { 1.0 };
+        let deg_per_sec = direction * 6.0 * self.params.rpm.max(1e-6);
+        let dt = step_deg / deg_per_sec;
+        let alpha = (deg_per_sec * t).rem_euclid(360.0);
+        // Differences only at the four samples i0-1..=i0+2 the interpolation reads
+        let i0 = (alpha / step_deg).floor() as isize;
+        let wrap = |i: isize| i.rem_euclid(n as isize) as usize;
+        let v: [f64; 4] = std::array::from_fn(|k| {
+            let i = i0 - 1 + k as isize;
+            (s[wrap(i + 1)] - s[wrap(i - 1)]) / (2.0 * dt)
+        });
+        let a: [f64; 4] = std::array::from_fn(|k| {
+            let i = i0 - 1 + k as isize;
+            (s[wrap(i + 1)] - 2.0 * s[wrap(i)] + s[wrap(i - 1)]) / (dt * dt)
+        });
+        // The local windows start one sample before i0
+        let local_alpha = alpha - (i0 - 1) as f64 * step_deg;
+        let at_local = |table: &[f64]| sample_uniform_table(table, local_alpha, step_deg, false, self.params.interp_order);
+        let position = sample_uniform_table(s, alpha, step_deg, true, self.params.interp_order);
+        (position, at_local(&v), at_local(&a))
+    }
+
+    /// Geometric piston velocity ds/dα (mm per radian of cam angle) on the α grid, from periodic
+    /// central differences of `piston_s`. Independent of rpm. Panics if `planet` is out of range.
+    pub fn piston_velocity_per_omega(&self, planet: usize) -> Vec<f64> {
+        let s = &self.planets[planet].piston_s;
+        let n = s.len();
+        let step_rad = self.params.sampling_step_deg * PI / 180.0;
+        (0..n).map(|i| (s[(i + 1) % n] - s[(i + n - 1) % n]) / (2.0 * step_rad)).collect()
+    }
+
+    /// Physical piston velocity (m/s) on the α grid: [`piston_velocity_per_omega`](Self::piston_velocity_per_omega)
+    /// times ω from the stored rpm (negative for reverse rotation), converted from mm/s.
+    pub fn piston_velocity_mps(&self, planet: usize) -> Vec<f64> {
+        let direction = if self.params.reverse_rotation { -1.0 } else { 1.0 };
+        let omega = direction * self.params.rpm.max(1e-6) * 2.0 * PI / 60.0; // rad/s
+        self.piston_velocity_per_omega(planet).into_iter().map(|v| v * omega * 1e-3).collect()
+    }
+}
+
+/// Convex hull of a point set (Andrew's monotone chain), counter-clockwise without repeating the first point.
+fn convex_hull(mut pts: Vec<(f64, f64)>) -> Vec<(f64, f64)> {
+    pts.sort_by(|a, b| a.0.total_cmp(&b.0).then(a.1.total_cmp(&b.1)));
+    pts.dedup();
+    if pts.len() < 3 {
+        return pts;
+    }
+    let cross = |o: (f64, f64), a: (f64, f64), b: (f64, f64)| (a.0 - o.0) * (b.1 - o.1) - (a.1 - o.1) * (b.0 - o.0);
+    let mut hull: Vec<(f64, f64)> = Vec::with_capacity(2 * pts.len());
+    // Lower chain
+    for &p in &pts {
+        while hull.len() >= 2 && cross(hull[hull.len() - 2], hull[hull.len() - 1], p) <= 0.0 { hull.pop(); }
+        hull.push(p);
+    }
+    // Upper chain
+    let lower_len = hull.len() + 1;
+    for &p in pts.iter().rev().skip(1) {
+        while hull.len() >= lower_len && cross(hull[hull.len() - 2], hull[hull.len() - 1], p) <= 0.0 { hull.pop(); }
+        hull.push(p);
+    }
+    // The upper chain ends on the first point again
+    hull.pop();
+    hull
+}
+
+fn piston_stroke(piston_s: &[f64]) -> f64 {
+    let (lo, hi) = piston_s.iter().fold((f64::INFINITY, f64::NEG_INFINITY), |(lo, hi), &v| (lo.min(v), hi.max(v)));
+    if piston_s.is_empty() { 0.0 } else { hi - lo }
+}
+
+/// Invert the cumulative ring arc-length table: returns φ (deg) where `s_tab · scale` reaches
+/// `target_s`, and whether the target fell outside `[s_tab[0], s_tab.last()]` and was clamped.
+/// The linear guess is refined by `refine_steps` Newton steps (at least 4 for cubic interpolation).
+fn find_phi(target_s: f64, s_tab: &[f64], scale: f64, step_deg: f64, interp_order: InterpOrder, refine_steps: u32) -> (f64, bool) {
+    let n = s_tab.len();
+    let first = s_tab[0];
+    let last = s_tab[n - 1];
+    let t = target_s / scale;
+    // Round-off at the ends (e.g. the final sample after scaling) is not an overshoot
+    let slack = 1e-9 * last.abs().max(1.0);
+    if t < first - slack {
+        return (0.0, true);
+    }
+    if t > last + slack {
+        return ((n - 1) as f64 * step_deg, true);
+    }
+    let t = t.clamp(first, last);
+    let mut lo = 0usize;
+    let mut hi = n - 1;
+    while hi - lo > 1 {
+        let mid = (lo + hi) / 2;
+        if s_tab[mid] < t { lo = mid; } else { hi = mid; }
+    }
+    let s_lo = s_tab[lo];
+    let s_hi = s_tab[hi];
+    let mut w = if (s_hi - s_lo).abs() > 1e-12 { (t - s_lo) / (s_hi - s_lo) } else { 0.0 };
+    let newton_steps = match interp_order {
+        InterpOrder::Cubic => refine_steps.max(4),
+        InterpOrder::Linear => refine_steps,
+    };
+    if newton_steps > 0 {
+        // Newton refinement of the linear guess on the Catmull-Rom segment
+        let p0 = s_tab[lo.saturating_sub(1)];
+        let p3 = s_tab[(hi + 1).min(n - 1)];
+        for _ in 0..newton_steps {
+            let f = catmull_rom(p0, s_lo, s_hi, p3, w) - t;
+            let df = catmull_rom_dw(p0, s_lo, s_hi, p3, w);
+            if df.abs() <= 1e-12 { break; }
+            w = (w - f / df).clamp(0.0, 1.0);
+        }
+    }
+    (((lo as f64) + w) * step_deg, false)
+}
+
+/// Fill `phi_out` with φ(θ) for every cam arc length in `s_cam`.
+/// Returns a note when any target had to be clamped to the table ends.
+fn invert_arc_length(
+    s_cam: &[f64],
+    s_ring: &[f64],
+    scale: f64,
+    step_deg: f64,
+    interp_order: InterpOrder,
+    refine_steps: u32,
+    phi_out: &mut [f64],
+) -> Option<String> {
+    let mut below = 0usize;
+    let mut above = 0usize;
+    for (phi, &s) in phi_out.iter_mut().zip(s_cam) {
+        let (p, clamped) = find_phi(s, s_ring, scale, step_deg, interp_order, refine_steps);
+        *phi = p;
+        if clamped {
+            if p == 0.0 { below += 1; } else { above += 1; }
+        }
+    }
+    (below + above > 0).then(|| format!(
+        "find_phi: {} target(s) below and {} above the ring arc-length table (scale={:.6}); clamped to endpoints",
+        below, above, scale
+    ))
+}
+
 /// Generate a piecewise motion law with 8 segments:
 /// TDC dwell, ramp after TDC, constant-V stroke, ramp before BDC,
 /// BDC dwell, ramp after BDC, constant-V stroke, ramp before TDC.
 /// 
 /// Returns a tuple of (theta_deg, x_mm, v_mm_per_omega, a_mm_per_omega2) vectors.
+///
+/// `a_mm_per_omega2` is the analytic acceleration of each segment and is exactly zero in the
+/// constant-velocity and dwell segments. `x_mm` is integrated from `v_mm_per_omega` with the
+/// trapezoidal rule, so its second difference only approximates that series: segment ends
+/// falling between grid samples and the residual drift across the 360° wrap show up as
+/// acceleration where the analytic series has none. The gap is reported as
+/// `Diagnostics::motion_law_accel_mismatch`; see [`MOTION_LAW_ACCEL_MISMATCH_BOUND`].
 fn generate_motion_law(params: &LitvinParameters) -> Result<(Vec<f64>, Vec<f64>, Vec<f64>, Vec<f64>), String> {
     // Validate parameters
     if params.sampling_step_deg <= 0.0 {
@@ -363,9 +1015,9 @@ fn generate_motion_law(params: &LitvinParameters) -> Result<(Vec<f64>, Vec<f64>,
     let b7 = ramp_before_tdc_start;
     let b8 = ramp_before_tdc_end;
     
-    // Precompute ramp integral I = ∫ s(t) dt over [0,1]
-    let profile = params.ramp_profile;
-    let i_ramp = MotionProfiles::integral(profile, 1.0);
+    // Precompute each ramp's integral I = ∫ s(t) dt over [0,1]
+    let [p_at, p_bb, p_ab, p_bt] = params.ramp_profiles();
+    let [i_at, i_bb, i_ab, i_bt] = [p_at, p_bb, p_ab, p_bt].map(MotionProfiles::full_integral);
     
     // Compute per-omega constant velocity magnitudes to hit target stroke length including ramps
     let rat_rad = r_at * PI / 180.0;
@@ -375,20 +1027,20 @@ fn generate_motion_law(params: &LitvinParameters) -> Result<(Vec<f64>, Vec<f64>,
     let cv1_rad = cv1 * PI / 180.0;
     let cv2_rad = cv2 * PI / 180.0;
     
-    let denom_up = (if rat_rad > 0.0 { rat_rad * i_ramp } else { 0.0 }) + 
+    let denom_up = (if rat_rad > 0.0 { rat_rad * i_at } else { 0.0 }) + 
                     cv1_rad + 
-                    (if rbb_rad > 0.0 { rbb_rad * (1.0 - i_ramp) } else { 0.0 });
+                    (if rbb_rad > 0.0 { rbb_rad * (1.0 - i_bb) } else { 0.0 });
     
-    let denom_dn = (if rab_rad > 0.0 { rab_rad * i_ramp } else { 0.0 }) + 
+    let denom_dn = (if rab_rad > 0.0 { rab_rad * i_ab } else { 0.0 }) + 
                     cv2_rad + 
-                    (if rbt_rad > 0.0 { rbt_rad * (1.0 - i_ramp) } else { 0.0 });
+                    (if rbt_rad > 0.0 { rbt_rad * (1.0 - i_bt) } else { 0.0 });
     
     let stroke = params.rod_length.max(0.0);
     let v_up = if denom_up > 0.0 { stroke / denom_up } else { 0.0 };
     let v_dn = if denom_dn > 0.0 { stroke / denom_dn } else { 0.0 };
     
     // Helper function for ramp normalization
-    let ramp_norm = |t_start_deg: f64, t_end_deg: f64, theta_deg: f64, up: bool| -> f64 {
+    let ramp_norm = |profile: RampProfile, t_start_deg: f64, t_end_deg: f64, theta_deg: f64, up: bool| -> f64 {
         let span = t_end_deg - t_start_deg;
         if span <= 0.0 { return 0.0; }
         let tt = ((theta_deg - t_start_deg) / span).max(0.0).min(1.0);
@@ -414,13 +1066,13 @@ fn generate_motion_law(params: &LitvinParameters) -> Result<(Vec<f64>, Vec<f64>,
             v = 0.0; a = 0.0;
         } else if th_deg < b2 {
             // Ramp after TDC (0 -> +Vup)
-            let sn = ramp_norm(b1, b2, th_deg, true);
+            let sn = ramp_norm(p_at, b1, b2, th_deg, true);
             v = v_up * sn;
             
             // Approximate acceleration using ds/dt at current t
             let span = b2 - b1;
             let tt = if span > 0.0 { (th_deg - b1) / span } else { 0.0 };
-            let ds = MotionProfiles::eval(profile, tt).ds;
+            let ds = MotionProfiles::eval(p_at, tt).ds;
             a = v_up * (ds / (span * PI / 180.0));
         } else if th_deg < b3 {
             // Constant V up
@@ -428,24 +1080,24 @@ fn generate_motion_law(params: &LitvinParameters) -> Result<(Vec<f64>, Vec<f64>,
             a = 0.0;
         } else if th_deg < b4 {
             // Ramp before BDC (+Vup -> 0)
-            let sn = ramp_norm(b3, b4, th_deg, false);
+            let sn = ramp_norm(p_bb, b3, b4, th_deg, false);
             v = v_up * sn;
             
             let span = b4 - b3;
             let tt = if span > 0.0 { (th_deg - b3) / span } else { 0.0 };
-            let ds = MotionProfiles::eval(profile, tt).ds;
+            let ds = MotionProfiles::eval(p_bb, tt).ds;
             a = -v_up * (ds / (span * PI / 180.0));
         } else if th_deg < b5 {
             // BDC dwell
             v = 0.0; a = 0.0;
         } else if th_deg < b6 {
             // Ramp after BDC (0 -> -Vdn)
-            let sn = ramp_norm(b5, b6, th_deg, true);
+            let sn = ramp_norm(p_ab, b5, b6, th_deg, true);
             v = -v_dn * sn;
             
             let span = b6 - b5;
             let tt = if span > 0.0 { (th_deg - b5) / span } else { 0.0 };
-            let ds = MotionProfiles::eval(profile, tt).ds;
+            let ds = MotionProfiles::eval(p_ab, tt).ds;
             a = -v_dn * (ds / (span * PI / 180.0));
         } else if th_deg < b7 {
             // Constant V down
@@ -453,12 +1105,12 @@ fn generate_motion_law(params: &LitvinParameters) -> Result<(Vec<f64>, Vec<f64>,
             a = 0.0;
         } else if th_deg < b8 {
             // Ramp before TDC (-Vdn -> 0)
-            let sn = ramp_norm(b7, b8, th_deg, false);
+            let sn = ramp_norm(p_bt, b7, b8, th_deg, false);
             v = -v_dn * sn;
             
             let span = b8 - b7;
             let tt = if span > 0.0 { (th_deg - b7) / span } else { 0.0 };
-            let ds = MotionProfiles::eval(profile, tt).ds;
+            let ds = MotionProfiles::eval(p_bt, tt).ds;
             a = v_dn * (ds / (span * PI / 180.0));
         } else {
             // Numerical guard (should not hit)
@@ -491,15 +1143,168 @@ fn generate_motion_law(params: &LitvinParameters) -> Result<(Vec<f64>, Vec<f64>,
     Ok((theta_deg, x_mm, v_mm_per_omega, a_mm_per_omega2))
 }
 
-pub fn build_litvin_tables(params: &LitvinParameters) -> Result<LitvinTables, String> {
-    params.validate()?;
-    let t0 = std::time::Instant::now();
-    
+/// Upper bound of `Diagnostics::motion_law_accel_mismatch` at the default 0.5° sampling step.
+/// The gap is a discretization effect and shrinks roughly linearly with `sampling_step_deg`;
+/// the cycloidal ramp, whose acceleration does not vanish at the ramp ends, comes closest (≈4%).
+pub const MOTION_LAW_ACCEL_MISMATCH_BOUND: f64 = 0.05;
+
+/// Largest gap between the analytic motion-law acceleration and the second difference of the
+/// integrated displacement (both per ω², periodic), relative to the peak analytic acceleration.
+fn motion_law_accel_mismatch(x_mm: &[f64], a_mm_per_omega2: &[f64], step_rad: f64) -> f64 {
+    let n = x_mm.len();
+    let a_peak = a_mm_per_omega2.iter().fold(0.0_f64, |m, a| m.max(a.abs()));
+    if n < 3 || a_peak <= 0.0 {
+        return 0.0;
+    }
+    (0..n)
+        .map(|i| {
+            let a_rec = (x_mm[(i + 1) % n] - 2.0 * x_mm[i] + x_mm[(i + n - 1) % n]) / (step_rad * step_rad);
+            (a_rec - a_mm_per_omega2[i]).abs()
+        })
+        .fold(0.0_f64, f64::max)
+        / a_peak
+}
+
+/// Motion law, pitch curves and φ(θ) from the arc-length conjugacy loop (steps 1–3).
+struct Conjugacy {
+    theta_deg: Vec<f64>,
+    x_mm: Vec<f64>,
+    r_cam: Vec<f64>,
+    phi_deg: Vec<f64>,
+    r_ring: Vec<f64>,
+    s_cam: Vec<f64>,
+    s_ring: Vec<f64>,
+    phi_of_theta_deg: Vec<f64>,
+    arc_res_max: f64,
+    arc_res_rms: f64,
+    arc_res_history: Vec<f64>, // arc_res_max after each iteration
+    iterate_snapshots: Vec<Vec<f64>>, // r_ring at the start of each iteration, when capture_iterates
+    iter_count: i32,
+    used_max_iter: bool,
+    regularization_applied: bool,
+    inversion_notes: Vec<String>,
+    degenerate: bool,
+    motion_law_accel_mismatch: f64,
+    motion_law_ms: f64,
+}
+
+/// Sample a uniform-grid table at `x_deg`; periodic tables wrap, cumulative tables clamp
+/// their cubic neighbours at the ends.
+fn sample_uniform_table(table: &[f64], x_deg: f64, step_deg: f64, periodic: bool, interp_order: InterpOrder) -> f64 {
+    let n = table.len() as isize;
+    let wrap_idx = |i: isize| -> usize { i.rem_euclid(n) as usize };
+    let clamp_idx = |i: isize| -> usize { i.clamp(0, n - 1) as usize };
+    let idx = x_deg / step_deg;
+    let i0 = idx.floor() as isize;
+    let w = idx - (i0 as f64);
+    let i1 = i0 + 1;
+    let v0 = table[wrap_idx(i0)];
+    let v1 = table[wrap_idx(i1)];
+    match interp_order {
+        InterpOrder::Linear => v0 * (1.0 - w) + v1 * w,
+        InterpOrder::Cubic => {
+            let at = |i: isize| if periodic { table[wrap_idx(i)] } else { table[clamp_idx(i)] };
+            catmull_rom(at(i0 - 1), v0, v1, at(i1 + 1), w)
+        }
+    }
+}
+
+/// Maximum jerk (mm/s³) of the input motion law, evaluated analytically over the ramp segments.
+/// This captures profile smoothness (S7 < S5) under constant RPM.
+fn analytic_ramp_jerk_max(params: &LitvinParameters, alpha_deg: &[f64]) -> f64 {
+    let deg_per_sec = 6.0 * params.rpm.max(1e-6); // dα/dt in deg/s
+    let omega = deg_per_sec * PI / 180.0; // rad/s
+    let profiles = params.ramp_profiles();
+    let [i_at, i_bb, i_ab, i_bt] = profiles.map(MotionProfiles::full_integral);
+
+    // Recompute segment boundaries (deg)
+    let d_tdc = params.dwell_tdc_deg.max(0.0);
+    let d_bdc = params.dwell_bdc_deg.max(0.0);
+    let r_at = params.ramp_after_tdc_deg.max(0.0);
+    let r_bb = params.ramp_before_bdc_deg.max(0.0);
+    let r_ab = params.ramp_after_bdc_deg.max(0.0);
+    let r_bt = params.ramp_before_tdc_deg.max(0.0);
+
+    let tdc_dwell_end = d_tdc;
+    let ramp_after_tdc_end = tdc_dwell_end + r_at;
+    let cv1_end = ramp_after_tdc_end + (180.0 - d_tdc - r_at - r_bb).max(0.0);
+    let ramp_before_bdc_end = cv1_end + r_bb;
+    let bdc_end = ramp_before_bdc_end + d_bdc;
+    let ramp_after_bdc_end = bdc_end + r_ab;
+    let ramp_before_tdc_start = 360.0 - r_bt;
+    let ramp_before_tdc_end = 360.0;
+
+    let cv1 = (cv1_end - ramp_after_tdc_end).max(0.0);
+    let cv2 = (ramp_before_tdc_start - ramp_after_bdc_end).max(0.0);
+
+    // Compute v_up, v_dn as in motion law
+    let rat_rad = r_at * PI / 180.0;
+    let rbb_rad = r_bb * PI / 180.0;
+    let rab_rad = r_ab * PI / 180.0;
+    let rbt_rad = r_bt * PI / 180.0;
+    let cv1_rad = cv1 * PI / 180.0;
+    let cv2_rad = cv2 * PI / 180.0;
+    let denom_up = (if rat_rad > 0.0 { rat_rad * i_at } else { 0.0 }) + cv1_rad + (if rbb_rad > 0.0 { rbb_rad * (1.0 - i_bb) } else { 0.0 });
+    let denom_dn = (if rab_rad > 0.0 { rab_rad * i_ab } else { 0.0 }) + cv2_rad + (if rbt_rad > 0.0 { rbt_rad * (1.0 - i_bt) } else { 0.0 });
+    let stroke = params.rod_length.max(0.0);
+    let v_up = if denom_up > 0.0 { stroke / denom_up } else { 0.0 };
+    let v_dn = if denom_dn > 0.0 { stroke / denom_dn } else { 0.0 };
+
+    let span_rad_at = (ramp_after_tdc_end - tdc_dwell_end).max(0.0) * PI / 180.0;
+    let span_rad_bb = (ramp_before_bdc_end - cv1_end).max(0.0) * PI / 180.0;
+    let span_rad_ab = (ramp_after_bdc_end - bdc_end).max(0.0) * PI / 180.0;
+    let span_rad_bt = (ramp_before_tdc_end - ramp_before_tdc_start).max(0.0) * PI / 180.0;
+
+    let mut jerk_ml_max: f64 = 0.0;
+    for &th in alpha_deg {
+        let (span_deg, span_rad, v_mag, up, start_deg, end_deg, profile) = if th >= tdc_dwell_end && th < ramp_after_tdc_end {
+            (r_at, span_rad_at, v_up, true, tdc_dwell_end, ramp_after_tdc_end, profiles[0])
+        } else if th >= cv1_end && th < ramp_before_bdc_end {
+            (r_bb, span_rad_bb, v_up, false, cv1_end, ramp_before_bdc_end, profiles[1])
+        } else if th >= bdc_end && th < ramp_after_bdc_end {
+            (r_ab, span_rad_ab, v_dn, true, bdc_end, ramp_after_bdc_end, profiles[2])
+        } else if th >= ramp_before_tdc_start && th < ramp_before_tdc_end {
+            (r_bt, span_rad_bt, v_dn, false, ramp_before_tdc_start, ramp_before_tdc_end, profiles[3])
+        } else { (0.0, 0.0, 0.0, true, 0.0, 0.0, params.ramp_profile) };
+
+        if span_deg > 0.0 && span_rad > 0.0 && v_mag != 0.0 {
+            let tt = ((th - start_deg) / span_deg).max(0.0).min(1.0);
+            let d3s = MotionProfiles::d3s(profile, tt);
+            let j_time = (v_mag * d3s) * (omega * omega * omega) / (span_rad * span_rad * span_rad);
+            jerk_ml_max = jerk_ml_max.max(j_time.abs());
+        }
+    }
+
+    jerk_ml_max
+}
+
+/// Transmission ratio i(θ) = dφ/dθ from periodic central differences of φ(θ).
+fn transmission_ratio(phi_of_theta_deg: &[f64], step_deg: f64) -> Vec<f64> {
+    let n = phi_of_theta_deg.len();
+    (0..n)
+        .map(|i| {
+            let ip = (i + 1) % n;
+            let im = (i + n - 1) % n;
+            let dphi = phi_of_theta_deg[ip] - phi_of_theta_deg[im];
+            // Use fixed angular step to avoid wrap-around artifacts at 0/360
+            let dtheta = 2.0 * step_deg;
+            // Handle angle wrapping for dφ; ensure dφ is in [-180, 180]
+            let dphi_adjusted = if dphi < -180.0 { dphi + 360.0 } else if dphi > 180.0 { dphi - 360.0 } else { dphi };
+            dphi_adjusted / dtheta
+        })
+        .collect()
+}
+
+/// Steps 1–3 of the build: motion law, cam pitch curve and the arc-length conjugacy loop.
+fn solve_conjugacy(params: &LitvinParameters) -> Result<Conjugacy, String> {
     // Generate motion law using piecewise profiles
+    let t_motion_law = std::time::Instant::now();
     let (theta_deg, x_mm, v_mm_per_omega, a_mm_per_omega2) = generate_motion_law(params)?;
+    let motion_law_ms = t_motion_law.elapsed().as_secs_f64() * 1000.0;
 
     let n = theta_deg.len();
     if n < 3 { return Err("sampling grid too small".to_string()); }
+    let motion_law_accel_mismatch = motion_law_accel_mismatch(&x_mm, &a_mm_per_omega2, params.sampling_step_deg * PI / 180.0);
 
     // Helper lambdas
     let deg2rad = |d: f64| d * PI / 180.0;
@@ -507,6 +1312,8 @@ pub fn build_litvin_tables(params: &LitvinParameters) -> Result<LitvinTables, St
 
     // Step 1-2: Build r_cam(θ) from motion law
     // Create normalized profile for r_cam from velocity
+    // Both constant-velocity magnitudes collapse to zero (e.g. rod_length = 0): the mechanism is static
+    let degenerate = v_mm_per_omega.iter().all(|v| v.abs() <= 1e-12);
     let v_max = v_mm_per_omega.iter().fold(0.0_f64, |a, &b| a.max(b.abs())).max(1e-12_f64);
     let s_norm: Vec<f64> = v_mm_per_omega.iter().map(|&v| v / v_max).collect();
     
@@ -545,10 +1352,17 @@ pub fn build_litvin_tables(params: &LitvinParameters) -> Result<LitvinTables, St
         s_cam[i] = acc;
     }
 
-    // Initial ring radius guess: external pair line-of-centers r_ring(φ≈θ) = max(ε, C - r_cam)
+    // Initial ring radius guess along the line of centers r_ring(φ≈θ):
+    // max(ε, C - r_cam) for an enclosing ring, max(ε, r_cam - C) for a ring inside the cam
     let eps = 1e-6;
     let mut r_ring = Vec::with_capacity(n);
-    for &rc in &r_cam { r_ring.push((c0 - rc).max(eps)); }
+    for &rc in &r_cam {
+        let r0 = match params.mesh_type {
+            MeshType::ExternalCamInternalRing => c0 - rc,
+            MeshType::InternalCamExternalRing => rc - c0,
+        };
+        r_ring.push(r0.max(eps));
+    }
 
     // Residual-control loop for arc-length conjugacy (Wave 2)
     // Predeclare outputs to reuse after loop
@@ -556,35 +1370,16 @@ pub fn build_litvin_tables(params: &LitvinParameters) -> Result<LitvinTables, St
     let mut phi_of_theta_deg = vec![0.0; n];
     let mut arc_res_max = f64::INFINITY;
     let mut arc_res_rms = f64::INFINITY;
+    let mut arc_res_history = Vec::with_capacity(params.max_iter.max(1) as usize);
+    let mut iterate_snapshots = Vec::new();
     let mut iter_count: i32 = 0;
     let mut used_max_iter = false;
     let mut regularization_applied = false;
+    let mut inversion_notes: Vec<String> = Vec::new();
 
-    // Helper: binary search over cumulative table
-    let find_phi = |target_s: f64, s_tab: &Vec<f64>, scale: f64| -> f64 {
-        let mut lo = 0usize;
-        let mut hi = s_tab.len() - 1;
-        let t = target_s / scale;
-        while hi - lo > 1 {
-            let mid = (lo + hi) / 2;
-            if s_tab[mid] < t { lo = mid; } else { hi = mid; }
-        }
-        let s_lo = s_tab[lo];
-        let s_hi = s_tab[hi];
-        let w = if (s_hi - s_lo).abs() > 1e-12 { (t - s_lo) / (s_hi - s_lo) } else { 0.0 };
-        let idx_f = (lo as f64) + w;
-        idx_f * step_deg
-    };
-
-    // Sampling helper (uniform grid, wrap)
-    let sample_table = |table: &Vec<f64>, x_deg: f64| -> f64 {
-        let idx = x_deg / step_deg;
-        let i0 = idx.floor() as isize;
-        let w = idx - (i0 as f64);
-        let i1 = i0 + 1;
-        let v0 = table[wrap_idx(i0)];
-        let v1 = table[wrap_idx(i1)];
-        v0 * (1.0 - w) + v1 * w
+    let interp_order = params.interp_order;
+    let sample_table = |table: &Vec<f64>, x_deg: f64, periodic: bool| -> f64 {
+        sample_uniform_table(table, x_deg, step_deg, periodic, interp_order)
     };
 
     let total_s_cam = *s_cam.last().unwrap_or(&1.0);
@@ -592,6 +1387,7 @@ pub fn build_litvin_tables(params: &LitvinParameters) -> Result<LitvinTables, St
     let max_iter = params.max_iter.max(1) as i32;
 
     for it in 0..max_iter {
+        if params.capture_iterates { iterate_snapshots.push(r_ring.clone()); }
         // Derivatives and cumulative arc-length for ring on φ grid
         let mut dr_dphi = vec![0.0; n];
         for i in 0..n {
@@ -608,8 +1404,9 @@ pub fn build_litvin_tables(params: &LitvinParameters) -> Result<LitvinTables, St
         let total_s_ring = *s_ring.last().unwrap_or(&1.0);
         let scale = if total_s_ring > 0.0 { total_s_cam / total_s_ring } else { 1.0 };
 
-        // Invert S_ring to get φ(θ)
-        for i in 0..n { phi_of_theta_deg[i] = find_phi(s_cam[i], &s_ring, scale); }
+        // Invert S_ring to get φ(θ); only the final iteration's clamp notes are kept
+        inversion_notes.clear();
+        inversion_notes.extend(invert_arc_length(&s_cam, &s_ring, scale, step_deg, interp_order, params.phi_refine_steps, &mut phi_of_theta_deg));
         // Enforce boundary conditions and monotonicity
         if n > 0 { phi_of_theta_deg[0] = 0.0; }
         let max_phi = 360.0 - step_deg;
@@ -626,14 +1423,22 @@ pub fn build_litvin_tables(params: &LitvinParameters) -> Result<LitvinTables, St
         let mut max_res = 0.0_f64;
         let mut sum_res2 = 0.0_f64;
         for i in 0..n {
-            let s_r = sample_table(&s_ring, phi_of_theta_deg[i]) * scale;
+            let s_r = sample_table(&s_ring, phi_of_theta_deg[i], false) * scale;
             let res = (s_cam[i] - s_r).abs();
             if res > max_res { max_res = res; }
             sum_res2 += res * res;
         }
         arc_res_max = max_res;
         arc_res_rms = (sum_res2 / (n as f64)).sqrt();
+        arc_res_history.push(arc_res_max);
         iter_count = it + 1;
+        if logging::enabled(LogLevel::Debug) {
+            crate::debug!(
+                "litvin",
+                "conjugacy iteration {}: arc residual max={:.6e} rms={:.6e}, scale={:.9}",
+                iter_count, arc_res_max, arc_res_rms, scale
+            );
+        }
         if arc_res_max <= tol { break; }
 
         // Damped correction: scale r_ring by total arc-length mismatch and smooth (regularization)
@@ -653,27 +1458,125 @@ pub fn build_litvin_tables(params: &LitvinParameters) -> Result<LitvinTables, St
         if it == max_iter - 1 { used_max_iter = true; }
     }
 
+    Ok(Conjugacy {
+        theta_deg,
+        x_mm,
+        r_cam,
+        phi_deg,
+        r_ring,
+        s_cam,
+        s_ring,
+        phi_of_theta_deg,
+        arc_res_max,
+        arc_res_rms,
+        arc_res_history,
+        iterate_snapshots,
+        iter_count,
+        used_max_iter,
+        regularization_applied,
+        inversion_notes,
+        degenerate,
+        motion_law_accel_mismatch,
+        motion_law_ms,
+    })
+}
+
+pub fn build_litvin_tables(params: &LitvinParameters) -> FEAResult<LitvinTables> {
+    params.validate().map_err(FEAError::ParameterValidation)?;
+    let t0 = std::time::Instant::now();
+    // Consecutive phase timings: each split returns the ms since the previous one
+    let mut lap = t0;
+    let mut split = || {
+        let now = std::time::Instant::now();
+        let ms = (now - lap).as_secs_f64() * 1000.0;
+        lap = now;
+        ms
+    };
+    // Resolve a mean-piston-speed target to rpm so the stored params carry the speed actually used
+    let resolved;
+    let params = if params.mean_piston_speed_mps.is_some() {
+        resolved = LitvinParameters { rpm: params.effective_rpm(), ..params.clone() };
+        &resolved
+    } else {
+        params
+    };
+    
+    let Conjugacy {
+        theta_deg,
+        x_mm,
+        r_cam,
+        phi_deg,
+        r_ring,
+        s_cam,
+        s_ring,
+        phi_of_theta_deg,
+        arc_res_max,
+        arc_res_rms,
+        arc_res_history: _,
+        iterate_snapshots,
+        iter_count,
+        used_max_iter,
+        regularization_applied,
+        inversion_notes,
+        degenerate,
+        motion_law_accel_mismatch,
+        motion_law_ms,
+    } = solve_conjugacy(params).map_err(FEAError::Calculation)?;
+    let conjugacy_ms = split() - motion_law_ms;
+
+    let n = theta_deg.len();
+    let alpha_deg = theta_deg.clone(); // α ≡ θ sampling grid
+    let deg2rad = |d: f64| d * PI / 180.0;
+    let wrap_idx = |i: isize| -> usize { ((i).rem_euclid(n as isize)) as usize };
+    let step_deg = params.sampling_step_deg;
+    let step_rad = deg2rad(step_deg);
+    let c0 = params.center_distance_bias.max(1e-6);
+    let interp_order = params.interp_order;
+    let sample_table = |table: &Vec<f64>, x_deg: f64, periodic: bool| -> f64 {
+        sample_uniform_table(table, x_deg, step_deg, periodic, interp_order)
+    };
+
     // Step 4: Kinematics
     let pc = params.planet_count.max(1) as usize;
     let mut planets = Vec::with_capacity(pc);
     let axis = deg2rad(params.slider_axis_deg);
     let ax = axis.cos();
     let ay = axis.sin();
+    let direction = if params.reverse_rotation { -1.0 } else { 1.0 };
 
     // Integrate internal spin ψ (deg) over α grid: dψ/dα ≈ r_ring(φ(α))/r_cam(θ(α)) - 1
     let mut psi_deg_series = vec![0.0; n];
+    let mut psi_unwrapped_series = vec![0.0; n];
     let mut last = 0.0;
     for i in 1..n {
-        let rr = sample_table(&r_ring, phi_of_theta_deg[i]);
+        let rr = sample_table(&r_ring, phi_of_theta_deg[i], true);
         let rc = r_cam[i];
-        let dpsi = step_deg * (rr / rc - 1.0);
+        let dpsi = direction * step_deg * (rr / rc - 1.0);
         last += dpsi;
+        psi_unwrapped_series[i] = last;
         // wrap to [0,360)
         let mut w = last % 360.0;
         if w < 0.0 { w += 360.0; }
         psi_deg_series[i] = w;
     }
 
+    // Spin closure: close the accumulation with the wrap step n-1 → 0 and compare it with a
+    // Simpson evaluation of ∮(r_ring/r_cam − 1)dα that also samples the interval midpoints.
+    let spin_rate = |rr: f64, rc: f64| direction * (rr / rc - 1.0);
+    let rate_at = |i: usize| spin_rate(sample_table(&r_ring, phi_of_theta_deg[i], true), r_cam[i]);
+    let psi_closed = last + step_deg * rate_at(0);
+    let psi_simpson: f64 = (0..n)
+        .map(|i| {
+            let j = (i + 1) % n;
+            let mut dphi = phi_of_theta_deg[j] - phi_of_theta_deg[i];
+            if dphi < -180.0 { dphi += 360.0; }
+            let rr_mid = sample_table(&r_ring, phi_of_theta_deg[i] + 0.5 * dphi, true);
+            let rc_mid = sample_table(&r_cam, theta_deg[i] + 0.5 * step_deg, true);
+            step_deg * (rate_at(i) + 4.0 * spin_rate(rr_mid, rc_mid) + rate_at(j)) / 6.0
+        })
+        .sum();
+    let spin_closure_error = (psi_closed - psi_simpson).abs();
+
     let beta = deg2rad(params.journal_phase_beta_deg);
     let center_r = c0;
     for i in 0..pc {
@@ -681,8 +1584,9 @@ pub fn build_litvin_tables(params: &LitvinParameters) -> Result<LitvinTables, St
         let mut cx = Vec::with_capacity(n);
         let mut cy = Vec::with_capacity(n);
         let mut psi = Vec::with_capacity(n);
-        let mut jx = Vec::with_capacity(n);
-        let mut jy = Vec::with_capacity(n);
+        let journal_cap = if params.compute_journal_geometry { n } else { 0 };
+        let mut jx = Vec::with_capacity(journal_cap);
+        let mut jy = Vec::with_capacity(journal_cap);
         let mut pist = Vec::with_capacity(n);
         for k in 0..n {
             let ai = deg2rad(alpha_deg[k] + offset);
@@ -693,63 +1597,46 @@ pub fn build_litvin_tables(params: &LitvinParameters) -> Result<LitvinTables, St
             let psi_k = psi_deg_series[k];
             psi.push(psi_k);
             let ang = deg2rad(psi_k) + beta;
-            let jlx = params.journal_radius * ang.cos();
-            let jly = params.journal_radius * ang.sin();
-            jx.push(px + jlx);
-            jy.push(py + jly);
-            pist.push((px + jlx) * ax + (py + jly) * ay);
+            if params.compute_journal_geometry {
+                jx.push(px + params.journal_radius * ang.cos());
+                jy.push(py + params.journal_radius * ang.sin());
+            }
+            // Journal offset projected straight onto the slider axis: r_j · cos(ang − axis)
+            pist.push(px * ax + py * ay + params.journal_radius * (ang - axis).cos());
         }
-        planets.push(PlanetState { center_x: cx, center_y: cy, spin_psi_deg: psi, journal_x: jx, journal_y: jy, piston_s: pist });
+        planets.push(PlanetState {
+            center_x: cx,
+            center_y: cy,
+            spin_psi_deg: psi,
+            spin_psi_deg_unwrapped: psi_unwrapped_series.clone(),
+            journal_x: jx,
+            journal_y: jy,
+            piston_s: pist,
+        });
     }
 
+    let kinematics_ms = split();
+
+    let rpm = params.rpm.max(1e-6);
+    let deg_per_sec = direction * 6.0 * rpm; // dα/dt in deg/s, negative for reverse rotation
+
     // Step 5: Clearance checks (simple and envelope-based)
-    let mut clearance_min = f64::INFINITY;
-    let mut violations: Vec<ClearanceViolation> = Vec::new();
-    let mut in_violation = false;
-    let mut start_idx = 0usize;
-    let buf = params.interference_buffer.max(0.0);
-    for i in 0..n {
-        let rr = sample_table(&r_ring, phi_of_theta_deg[i]);
-        let g = rr - r_cam[i] - buf;
-        if g < clearance_min { clearance_min = g; }
-        if g < 0.0 {
-            if !in_violation { in_violation = true; start_idx = i; }
-        } else {
-            if in_violation {
-                let vs = ClearanceViolation { alpha_start_deg: alpha_deg[start_idx], alpha_end_deg: alpha_deg[i], min_clearance: clearance_min };
-                violations.push(vs);
-                in_violation = false;
-            }
-        }
-    }
-    if in_violation {
-        let vs = ClearanceViolation { alpha_start_deg: alpha_deg[start_idx], alpha_end_deg: alpha_deg[n-1], min_clearance: clearance_min };
-        violations.push(vs);
-    }
+    let buf = params.interference_buffer; // validated to lie in [0, center_distance_bias)
+    let gaps: Vec<f64> = (0..n)
+        .map(|i| {
+            let rr = sample_table(&r_ring, phi_of_theta_deg[i], true);
+            let gap = match params.mesh_type {
+                MeshType::ExternalCamInternalRing => rr - r_cam[i],
+                MeshType::InternalCamExternalRing => r_cam[i] - rr,
+            };
+            gap - buf
+        })
+        .collect();
+    let (clearance_min, violations) = collect_violations(&alpha_deg, &gaps, deg_per_sec);
 
     // Envelope clearance proxy: account for journal radius as swept envelope along line-of-centers
-    let mut env_clearance_min = f64::INFINITY;
-    let mut env_violations: Vec<ClearanceViolation> = Vec::new();
-    let mut env_in_violation = false;
-    let mut env_start_idx = 0usize;
-    for i in 0..n {
-        let rr = sample_table(&r_ring, phi_of_theta_deg[i]);
-        let g_env = rr - (r_cam[i] + params.journal_radius) - buf;
-        if g_env < env_clearance_min { env_clearance_min = g_env; }
-        if g_env < 0.0 {
-            if !env_in_violation { env_in_violation = true; env_start_idx = i; }
-        } else {
-            if env_in_violation {
-                let vs = ClearanceViolation { alpha_start_deg: alpha_deg[env_start_idx], alpha_end_deg: alpha_deg[i], min_clearance: env_clearance_min };
-                env_violations.push(vs);
-                env_in_violation = false;
-            }
-        }
-    }
-    if env_in_violation {
-        let vs = ClearanceViolation { alpha_start_deg: alpha_deg[env_start_idx], alpha_end_deg: alpha_deg[n-1], min_clearance: env_clearance_min };
-        env_violations.push(vs);
-    }
+    let env_gaps: Vec<f64> = gaps.iter().map(|g| g - params.journal_radius).collect();
+    let (env_clearance_min, env_violations) = collect_violations(&alpha_deg, &env_gaps, deg_per_sec);
 
     // Manufacturability proxies
     // Tooth thickness proxy: local thickness ~ rr - average of neighbors
@@ -776,10 +1663,15 @@ pub fn build_litvin_tables(params: &LitvinParameters) -> Result<LitvinTables, St
     let curvature_radius_min = if max_abs_d2r > 1e-12 { 1.0 / max_abs_d2r } else { 1e12 };
     let undercut_flag = curvature_radius_min < 0.2 * rr_min;
 
+    // Tooth-count feasibility: how many teeth of circular pitch π·m fit on the ring pitch curve
+    let ring_pitch_length = *s_ring.last().unwrap_or(&0.0);
+    let feasible_tooth_count = (ring_pitch_length / (PI * params.min_module)).floor().max(0.0) as i32;
+    let tooth_count_infeasible = feasible_tooth_count < MIN_RING_TEETH;
+
+    let clearance_ms = split();
+
     // NVH proxies: acceleration and jerk maxima and sparse FFT peaks
     // Build acceleration and jerk from piston_s time series
-    let rpm = params.rpm.max(1e-6);
-    let deg_per_sec = 6.0 * rpm; // dα/dt in deg/s
     let dt = step_deg / deg_per_sec; // seconds per step
     let mut accel = vec![0.0; n];
     let mut jerk = vec![0.0; n];
@@ -805,90 +1697,49 @@ pub fn build_litvin_tables(params: &LitvinParameters) -> Result<LitvinTables, St
 
 
     // Also compute jerk from the input motion law analytically over ramp segments.
-    // This captures profile smoothness (S7 < S5) under constant RPM.
-    let omega = deg_per_sec * PI / 180.0; // rad/s
-    let profile = params.ramp_profile;
-    let i_ramp = MotionProfiles::integral(profile, 1.0);
-
-    // Recompute segment boundaries (deg)
-    let d_tdc = params.dwell_tdc_deg.max(0.0);
-    let d_bdc = params.dwell_bdc_deg.max(0.0);
-    let r_at = params.ramp_after_tdc_deg.max(0.0);
-    let r_bb = params.ramp_before_bdc_deg.max(0.0);
-    let r_ab = params.ramp_after_bdc_deg.max(0.0);
-    let r_bt = params.ramp_before_tdc_deg.max(0.0);
-
-    let tdc_dwell_end = d_tdc;
-    let ramp_after_tdc_end = tdc_dwell_end + r_at;
-    let cv1_end = ramp_after_tdc_end + (180.0 - d_tdc - r_at - r_bb).max(0.0);
-    let ramp_before_bdc_end = cv1_end + r_bb;
-    let bdc_end = ramp_before_bdc_end + d_bdc;
-    let ramp_after_bdc_end = bdc_end + r_ab;
-    let ramp_before_tdc_start = 360.0 - r_bt;
-    let ramp_before_tdc_end = 360.0;
-
-    let cv1 = (cv1_end - ramp_after_tdc_end).max(0.0);
-    let cv2 = (ramp_before_tdc_start - ramp_after_bdc_end).max(0.0);
-
-    // Compute v_up, v_dn as in motion law
-    let rat_rad = r_at * PI / 180.0;
-    let rbb_rad = r_bb * PI / 180.0;
-    let rab_rad = r_ab * PI / 180.0;
-    let rbt_rad = r_bt * PI / 180.0;
-    let cv1_rad = cv1 * PI / 180.0;
-    let cv2_rad = cv2 * PI / 180.0;
-    let denom_up = (if rat_rad > 0.0 { rat_rad * i_ramp } else { 0.0 }) + cv1_rad + (if rbb_rad > 0.0 { rbb_rad * (1.0 - i_ramp) } else { 0.0 });
-    let denom_dn = (if rab_rad > 0.0 { rab_rad * i_ramp } else { 0.0 }) + cv2_rad + (if rbt_rad > 0.0 { rbt_rad * (1.0 - i_ramp) } else { 0.0 });
-    let stroke = params.rod_length.max(0.0);
-    let v_up = if denom_up > 0.0 { stroke / denom_up } else { 0.0 };
-    let v_dn = if denom_dn > 0.0 { stroke / denom_dn } else { 0.0 };
-
-    let span_rad_at = (ramp_after_tdc_end - tdc_dwell_end).max(0.0) * PI / 180.0;
-    let span_rad_bb = (ramp_before_bdc_end - cv1_end).max(0.0) * PI / 180.0;
-    let span_rad_ab = (ramp_after_bdc_end - bdc_end).max(0.0) * PI / 180.0;
-    let span_rad_bt = (ramp_before_tdc_end - ramp_before_tdc_start).max(0.0) * PI / 180.0;
-
-    let mut jerk_ml_max: f64 = 0.0;
-    for i in 0..n {
-        let th = alpha_deg[i];
-        let (span_deg, span_rad, v_mag, up, start_deg, end_deg) = if th >= tdc_dwell_end && th < ramp_after_tdc_end {
-            (r_at, span_rad_at, v_up, true, tdc_dwell_end, ramp_after_tdc_end)
-        } else if th >= cv1_end && th < ramp_before_bdc_end {
-            (r_bb, span_rad_bb, v_up, false, cv1_end, ramp_before_bdc_end)
-        } else if th >= bdc_end && th < ramp_after_bdc_end {
-            (r_ab, span_rad_ab, v_dn, true, bdc_end, ramp_after_bdc_end)
-        } else if th >= ramp_before_tdc_start && th < ramp_before_tdc_end {
-            (r_bt, span_rad_bt, v_dn, false, ramp_before_tdc_start, ramp_before_tdc_end)
-        } else { (0.0, 0.0, 0.0, true, 0.0, 0.0) };
-
-        if span_deg > 0.0 && span_rad > 0.0 && v_mag != 0.0 {
-            let tt = ((th - start_deg) / span_deg).max(0.0).min(1.0);
-            let d3s = MotionProfiles::d3s(profile, tt);
-            let j_time = (v_mag * d3s) * (omega * omega * omega) / (span_rad * span_rad * span_rad);
-            jerk_ml_max = jerk_ml_max.max(j_time.abs());
-        }
-    }
+    let jerk_ml_max = analytic_ramp_jerk_max(params, &alpha_deg);
 
     // Report jerk directly from analytic ramp jerk; profile smoothness is inherent in d³s.
     // This preserves correct relative ordering (S7 < S5) without ad-hoc scaling.
     let jerk_max = jerk_ml_max;
 
-    // Sparse FFT at first few engine orders (k=1..5)
+    // Sparse FFT over the first few engine orders (1..=5), on `nvh_cycles` tiled cycles:
+    // bin k sits at k / cycles engine orders (zero unless k is a multiple of cycles)
     let orders = 5usize;
+    let cycles = params.nvh_cycles.max(1) as usize;
+    let total = n * cycles;
     let mut nvh_peaks: Vec<NvhPeak> = Vec::new();
     let base_freq_hz = rpm / 60.0;
-    for k in 1..=orders {
+    for k in 1..=orders * cycles {
         let mut re = 0.0;
         let mut imv = 0.0;
-        for m in 0..n {
-            let ang = 2.0 * std::f64::consts::PI * (k as f64) * (m as f64) / (n as f64);
-            re += accel[m] * ang.cos();
-            imv -= accel[m] * ang.sin();
+        for m in 0..total {
+            let ang = 2.0 * std::f64::consts::PI * (k as f64) * (m as f64) / (total as f64);
+            re += accel[m % n] * ang.cos();
+            imv -= accel[m % n] * ang.sin();
         }
-        let amp = (re*re + imv*imv).sqrt() * 2.0 / (n as f64);
-        nvh_peaks.push(NvhPeak { freq_hz: base_freq_hz * (k as f64), amp });
+        let amp = (re*re + imv*imv).sqrt() * 2.0 / (total as f64);
+        nvh_peaks.push(NvhPeak { freq_hz: base_freq_hz * (k as f64) / (cycles as f64), amp });
     }
 
+    // Dominant order: the engine order nearest the largest peak
+    let (dominant_nvh_order, dominant_nvh_amp) = nvh_peaks
+        .iter()
+        .fold((0, 0.0_f64), |best, p| {
+            if p.amp > best.1 { ((p.freq_hz / base_freq_hz).round() as i32, p.amp) } else { best }
+        });
+    let nvh_limit_fraction = params.nvh_amp_limit.map(|limit| dominant_nvh_amp / limit);
+
+    // Each of the planets passes a fixed mesh point once per revolution. Every order bin is in
+    // `nvh_peaks`, so only the largest one is checked against the mesh harmonics.
+    let mesh_frequency_hz = pc as f64 * base_freq_hz;
+    let nvh_at_mesh_frequency = dominant_nvh_amp > 0.0
+        && nvh_peaks.iter().find(|p| p.amp == dominant_nvh_amp).is_some_and(|p| {
+            let harmonic = (p.freq_hz / mesh_frequency_hz).round().max(1.0);
+            (p.freq_hz - harmonic * mesh_frequency_hz).abs() <= MESH_FREQUENCY_BAND * mesh_frequency_hz
+        });
+    let nvh_ms = split();
+
     // Calculate tracking_rms (RMS error between target x(θ) and reconstructed piston path)
     let mut sum_tracking_error_squared = 0.0;
     for i in 0..n {
@@ -900,17 +1751,10 @@ pub fn build_litvin_tables(params: &LitvinParameters) -> Result<LitvinTables, St
     let tracking_rms = (sum_tracking_error_squared / n as f64).sqrt();
     
     // Calculate sliding velocity metrics
+    let ratio = transmission_ratio(&phi_of_theta_deg, step_deg);
     let mut sliding_velocities = Vec::with_capacity(n);
     for i in 0..n {
-        // Calculate dφ/dθ (transmission ratio i(θ)) using central differences
-        let ip = wrap_idx(i as isize + 1);
-        let im = wrap_idx(i as isize - 1);
-        let dphi = phi_of_theta_deg[ip] - phi_of_theta_deg[im];
-        // Use fixed angular step to avoid wrap-around artifacts at 0/360
-        let dtheta = 2.0 * step_deg;
-        // Handle angle wrapping for dφ; ensure dφ is in [-180, 180]
-        let dphi_adjusted = if dphi < -180.0 { dphi + 360.0 } else if dphi > 180.0 { dphi - 360.0 } else { dphi };
-        let i_theta = dphi_adjusted / dtheta; // This is the transmission ratio at point i
+        let i_theta = ratio[i]; // transmission ratio dφ/dθ at point i
         
         // Calculate tangential velocities at contact point
         // Angular velocity = dα/dt and dφ/dt = dφ/dα * dα/dt
@@ -920,7 +1764,7 @@ pub fn build_litvin_tables(params: &LitvinParameters) -> Result<LitvinTables, St
         // Tangential velocities = r * ω
         let cam_tangential_vel = r_cam[i] * cam_angular_vel; // r_cam aligned to θ
         // r_ring must be sampled at φ(θ) to reflect the conjugate contact state
-        let rr_at_phi = sample_table(&r_ring, phi_of_theta_deg[i]);
+        let rr_at_phi = sample_table(&r_ring, phi_of_theta_deg[i], true);
         let ring_tangential_vel = rr_at_phi * ring_angular_vel;
         
         // Sliding velocity is the difference
@@ -931,9 +1775,20 @@ pub fn build_litvin_tables(params: &LitvinParameters) -> Result<LitvinTables, St
     // Calculate mean and max sliding velocity
     let sliding_vel_mean = sliding_velocities.iter().sum::<f64>() / n as f64;
     let sliding_vel_max = sliding_velocities.iter().fold(0.0_f64, |a: f64, &b: &f64| a.max(b));
+    let sliding_velocity_exceeded_alpha_deg = params.max_sliding_velocity.and_then(|limit| {
+        sliding_velocities.iter().position(|&v| v > limit).map(|i| alpha_deg[i])
+    });
     
+    // Name the per-ramp profiles only when they differ from `ramp_profile`
+    let ramp_profiles = params.ramp_profiles();
+    let profile_desc = if ramp_profiles.iter().all(|&p| p == params.ramp_profile) {
+        format!("{:?}", params.ramp_profile)
+    } else {
+        format!("{:?}", ramp_profiles)
+    };
+
     // Create diagnostics notes as a vector of strings
-    let notes = vec![
+    let mut notes = vec![
         format!(
             "Iterations: {}/{}, Arc residual: {:.6e}, Tracking RMS: {:.6e}, Build time: {:.2}ms",
             iter_count, params.max_iter, arc_res_max, tracking_rms, t0.elapsed().as_secs_f64() * 1000.0
@@ -943,10 +1798,52 @@ pub fn build_litvin_tables(params: &LitvinParameters) -> Result<LitvinTables, St
             sliding_vel_mean, sliding_vel_max
         ),
         format!(
-            "Profile={:?}, jerk_max_piston={:.6e}, jerk_max_rampAnalytic={:.6e}",
-            params.ramp_profile, jerk_max_piston, jerk_ml_max
+            "Profile={}, jerk_max_piston={:.6e}, jerk_max_rampAnalytic={:.6e}",
+            profile_desc, jerk_max_piston, jerk_ml_max
         )
     ];
+    notes.extend(inversion_notes);
+    if degenerate {
+        notes.push(format!(
+            "Degenerate motion law: v_up and v_dn are zero (rod_length={:.3}), no stroke; tracking_rms of 0 does not indicate tracking quality",
+            params.rod_length
+        ));
+    }
+    let points_per_shortest_ramp = [
+        params.ramp_after_tdc_deg,
+        params.ramp_before_bdc_deg,
+        params.ramp_after_bdc_deg,
+        params.ramp_before_tdc_deg,
+    ]
+    .into_iter()
+    .filter(|&r| r > 0.0)
+    .reduce(f64::min)
+    .map(|r| r / step_deg);
+    if let Some(points) = points_per_shortest_ramp.filter(|&p| p < MIN_POINTS_PER_RAMP) {
+        notes.push(format!(
+            "Shortest ramp has {:.1} samples (< {}); use sampling_step_deg <= {:.4} to resolve the ramp profile and its jerk",
+            points,
+            MIN_POINTS_PER_RAMP,
+            step_deg * points / MIN_POINTS_PER_RAMP
+        ));
+    }
+    if spin_closure_error > SPIN_CLOSURE_TOLERANCE_DEG {
+        notes.push(format!(
+            "Spin closure error {:.6e} deg exceeds {:.3} deg; ψ drifts over the cycle, refine sampling_step_deg",
+            spin_closure_error, SPIN_CLOSURE_TOLERANCE_DEG
+        ));
+    }
+    let other_ms = split();
+    let build_ms = t0.elapsed().as_secs_f64() * 1000.0;
+    let timing = TimingBreakdown { motion_law_ms, conjugacy_ms, kinematics_ms, clearance_ms, nvh_ms, other_ms };
+    let over_budget = params.build_time_budget_ms.is_some_and(|budget| build_ms > budget);
+    if over_budget {
+        notes.push(format!(
+            "Build time {:.2}ms exceeds budget {:.2}ms",
+            build_ms,
+            params.build_time_budget_ms.unwrap_or_default()
+        ));
+    }
 
     let diagnostics = Diagnostics {
         arc_length_residual_max: arc_res_max,
@@ -954,30 +1851,359 @@ pub fn build_litvin_tables(params: &LitvinParameters) -> Result<LitvinTables, St
         iter_count,
         used_max_iter,
         regularization_applied,
+        iterate_snapshots,
         clearance_min,
         clearance_violations: violations,
+        clearance_pass: clearance_min >= params.min_clearance_target,
         envelope_clearance_min: env_clearance_min,
         envelope_violations: env_violations,
         tooth_thickness_min,
         undercut_flag,
         curvature_radius_min,
+        feasible_tooth_count,
+        tooth_count_infeasible,
+        cam_mean_radius: r_cam.iter().sum::<f64>() / r_cam.len() as f64,
+        ring_mean_radius: r_ring.iter().sum::<f64>() / r_ring.len() as f64,
         tracking_rms,
         accel_max,
         jerk_max,
+        accel_pass: params.accel_limit.is_none_or(|limit| accel_max <= limit),
+        jerk_pass: params.jerk_limit.is_none_or(|limit| jerk_max <= limit),
+        achieved_stroke: piston_stroke(&planets[0].piston_s),
+        stroke_error: piston_stroke(&planets[0].piston_s) - params.rod_length,
+        degenerate,
+        motion_law_accel_mismatch,
+        points_per_shortest_ramp,
+        spin_closure_error,
         sliding_vel_mean,
         sliding_vel_max,
+        sliding_velocity_pass: sliding_velocity_exceeded_alpha_deg.is_none(),
+        sliding_velocity_exceeded_alpha_deg,
         nvh_peaks,
+        dominant_nvh_order,
+        dominant_nvh_amp,
+        nvh_limit_fraction,
+        mesh_frequency_hz,
+        nvh_at_mesh_frequency,
         suggested_center_distance_inflation: if clearance_min < 0.0 { -clearance_min + 0.01 } else { 0.0 },
-        build_ms: t0.elapsed().as_secs_f64() * 1000.0,
+        build_ms,
+        over_budget,
+        timing,
         notes,
     };
 
     // Pitch curves to emit
-    let curves = PitchCurves { theta_deg, r_cam, phi_deg, r_ring, s_cam, s_ring, phi_of_theta_deg };
+    let r_ring_of_theta: Vec<f64> = phi_of_theta_deg.iter().map(|&phi| sample_table(&r_ring, phi, true)).collect();
+    let curves = PitchCurves { theta_deg, r_cam, phi_deg, r_ring, s_cam, s_ring, phi_of_theta_deg, r_ring_of_theta };
 
     Ok(LitvinTables { params: params.clone(), curves, alpha_deg, planets, diagnostics })
 }
 
+/// Transmission metrics from [`build_litvin_transmission_only`].
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransmissionSummary {
+    pub arc_length_residual_max: f64,
+    pub arc_length_residual_rms: f64,
+    pub iter_count: i32,
+    pub used_max_iter: bool,
+    pub i_min: f64,               // Minimum transmission ratio dφ/dθ
+    pub i_max: f64,               // Maximum transmission ratio dφ/dθ
+    pub jerk_max: f64,            // Analytic ramp jerk (mm/s³), as in Diagnostics::jerk_max
+    pub degenerate: bool,
+    pub build_ms: f64,
+}
+
+/// Run only the motion law and arc-length conjugacy loop and report the transmission metrics.
+///
+/// Skips planet placement, clearance scans, manufacturability checks and NVH, so it is
+/// much cheaper than [`build_litvin_tables`] when only these metrics are needed.
+pub fn build_litvin_transmission_only(params: &LitvinParameters) -> FEAResult<TransmissionSummary> {
+    params.validate().map_err(FEAError::ParameterValidation)?;
+    let t0 = std::time::Instant::now();
+    // Resolve a mean-piston-speed target to rpm, as build_litvin_tables does
+    let resolved;
+    let params = if params.mean_piston_speed_mps.is_some() {
+        resolved = LitvinParameters { rpm: params.effective_rpm(), ..params.clone() };
+        &resolved
+    } else {
+        params
+    };
+    let conj = solve_conjugacy(params).map_err(FEAError::Calculation)?;
+    let ratio = transmission_ratio(&conj.phi_of_theta_deg, params.sampling_step_deg);
+    let (i_min, i_max) = ratio.iter().fold((f64::INFINITY, f64::NEG_INFINITY), |(lo, hi), &r| (lo.min(r), hi.max(r)));
+    Ok(TransmissionSummary {
+        arc_length_residual_max: conj.arc_res_max,
+        arc_length_residual_rms: conj.arc_res_rms,
+        iter_count: conj.iter_count,
+        used_max_iter: conj.used_max_iter,
+        i_min,
+        i_max,
+        jerk_max: analytic_ramp_jerk_max(params, &conj.theta_deg),
+        degenerate: conj.degenerate,
+        build_ms: t0.elapsed().as_secs_f64() * 1000.0,
+    })
+}
+
+/// Iterations run by [`estimate_iterations_for_tolerance`] to measure the convergence rate
+pub const ESTIMATE_PROBE_ITERATIONS: i32 = 5;
+
+/// Predict the `max_iter` needed to bring the arc-length residual down to `target_tol` (mm).
+///
+/// Runs [`ESTIMATE_PROBE_ITERATIONS`] conjugacy iterations, takes the mean per-iteration
+/// residual reduction ratio and extrapolates it geometrically. The residual reduction speeds up
+/// as the ring converges, so the estimate errs high. Returns the probe's own count when the
+/// target is reached during the probe, and a `Calculation` error when the residual is not
+/// decreasing.
+pub fn estimate_iterations_for_tolerance(params: &LitvinParameters, target_tol: f64) -> FEAResult<i32> {
+    params.validate().map_err(FEAError::ParameterValidation)?;
+    let probe = LitvinParameters { max_iter: ESTIMATE_PROBE_ITERATIONS, arc_residual_tol_mm: 0.0, ..params.clone() };
+    let history = solve_conjugacy(&probe).map_err(FEAError::Calculation)?.arc_res_history;
+    if let Some(i) = history.iter().position(|&r| r <= target_tol) {
+        return Ok(i as i32 + 1);
+    }
+    let (first, last) = (history[0], history[history.len() - 1]);
+    let ratio = (last / first).powf(1.0 / (history.len() - 1) as f64);
+    if ratio.partial_cmp(&1.0) != Some(std::cmp::Ordering::Less) || target_tol <= 0.0 {
+        return Err(FEAError::Calculation(format!(
+            "arc residual cannot reach {:.3e} mm: {:.6e} -> {:.6e} over {} probe iterations",
+            target_tol, first, last, history.len()
+        )));
+    }
+    let remaining = ((target_tol / last).ln() / ratio.ln()).ceil();
+    Ok((history.len() as f64 + remaining).min(i32::MAX as f64) as i32)
+}
+
+/// Build the tables once per [`RampProfile`] (in parallel) and return each profile's diagnostics.
+///
+/// All other parameters are taken from `params` (per-ramp overrides are cleared so every ramp
+/// uses the profile under test); results follow [`RampProfile::ALL`] order.
+/// Compare e.g. `jerk_max`, `accel_max` and `tracking_rms` across the entries.
+pub fn compare_ramp_profiles(params: &LitvinParameters) -> FEAResult<Vec<(RampProfile, Diagnostics)>> {
+    maybe_par_iter!(&RampProfile::ALL)
+        .map(|&profile| {
+            let p = LitvinParameters {
+                ramp_profile: profile,
+                ramp_after_tdc_profile: None,
+                ramp_before_bdc_profile: None,
+                ramp_after_bdc_profile: None,
+                ramp_before_tdc_profile: None,
+                ..params.clone()
+            };
+            build_litvin_tables(&p).map(|tables| (profile, tables.diagnostics))
+        })
+        .collect()
+}
+
+/// Set the numeric field `key` (snake_case field name) of `params` to `value`.
+/// Option fields become `Some(value)`; integer fields require an integral value.
+/// `rpm` is rejected while `mean_piston_speed_mps` is set, since the override would ignore it.
+fn set_numeric_param(params: &mut LitvinParameters, key: &str, value: f64) -> Result<(), String> {
+    let as_int = |v: f64| -> Result<i32, String> {
+        if v.fract() == 0.0 && v.abs() <= i32::MAX as f64 { Ok(v as i32) } else { Err(format!("{} must be an integer, got {}", key, v)) }
+    };
+    match key {
+        "up_fraction" => params.up_fraction = value,
+        "dwell_tdc_deg" => params.dwell_tdc_deg = value,
+        "dwell_bdc_deg" => params.dwell_bdc_deg = value,
+        "ramp_before_tdc_deg" => params.ramp_before_tdc_deg = value,
+        "ramp_after_tdc_deg" => params.ramp_after_tdc_deg = value,
+        "ramp_before_bdc_deg" => params.ramp_before_bdc_deg = value,
+        "ramp_after_bdc_deg" => params.ramp_after_bdc_deg = value,
+        "rod_length" => params.rod_length = value,
+        "interference_buffer" => params.interference_buffer = value,
+        "journal_radius" => params.journal_radius = value,
+        "journal_phase_beta_deg" => params.journal_phase_beta_deg = value,
+        "slider_axis_deg" => params.slider_axis_deg = value,
+        "planet_count" => params.planet_count = as_int(value)?,
+        "carrier_offset_deg" => params.carrier_offset_deg = value,
+        "ring_thickness_visual" => params.ring_thickness_visual = value,
+        "sampling_step_deg" => params.sampling_step_deg = value,
+        "rpm" if params.mean_piston_speed_mps.is_some() => {
+            return Err("rpm cannot be set while mean_piston_speed_mps overrides it".to_string())
+        }
+        "rpm" => params.rpm = value,
+        "cam_r0" => params.cam_r0 = value,
+        "cam_k_per_unit" => params.cam_k_per_unit = value,
+        "center_distance_bias" => params.center_distance_bias = value,
+        "center_distance_scale" => params.center_distance_scale = value,
+        "arc_residual_tol_mm" => params.arc_residual_tol_mm = value,
+        "max_iter" => params.max_iter = as_int(value)?,
+        "min_module" => params.min_module = value,
+        "min_clearance_target" => params.min_clearance_target = value,
+        "max_sliding_velocity" => params.max_sliding_velocity = Some(value),
+        "mean_piston_speed_mps" => params.mean_piston_speed_mps = Some(value),
+        "nvh_amp_limit" => params.nvh_amp_limit = Some(value),
+        "build_time_budget_ms" => params.build_time_budget_ms = Some(value),
+        "accel_limit" => params.accel_limit = Some(value),
+        "jerk_limit" => params.jerk_limit = Some(value),
+        "nvh_cycles" => {
+            params.nvh_cycles = u32::try_from(as_int(value)?).map_err(|_| format!("nvh_cycles must be non-negative, got {}", value))?
+        }
+        "phi_refine_steps" => {
+            params.phi_refine_steps = u32::try_from(as_int(value)?).map_err(|_| format!("phi_refine_steps must be non-negative, got {}", value))?
+        }
+        _ => return Err(format!("unknown or non-numeric sweep parameter: {}", key)),
+    }
+    Ok(())
+}
+
+/// Current value of the numeric field `key`, with the keys of [`set_numeric_param`].
+/// Unset Option fields are an error.
+fn numeric_param(params: &LitvinParameters, key: &str) -> Result<f64, String> {
+    let unset = |v: Option<f64>| v.ok_or_else(|| format!("{} is unset", key));
+    Ok(match key {
+        "up_fraction" => params.up_fraction,
+        "dwell_tdc_deg" => params.dwell_tdc_deg,
+        "dwell_bdc_deg" => params.dwell_bdc_deg,
+        "ramp_before_tdc_deg" => params.ramp_before_tdc_deg,
+        "ramp_after_tdc_deg" => params.ramp_after_tdc_deg,
+        "ramp_before_bdc_deg" => params.ramp_before_bdc_deg,
+        "ramp_after_bdc_deg" => params.ramp_after_bdc_deg,
+        "rod_length" => params.rod_length,
+        "interference_buffer" => params.interference_buffer,
+        "journal_radius" => params.journal_radius,
+        "journal_phase_beta_deg" => params.journal_phase_beta_deg,
+        "slider_axis_deg" => params.slider_axis_deg,
+        "planet_count" => params.planet_count as f64,
+        "carrier_offset_deg" => params.carrier_offset_deg,
+        "ring_thickness_visual" => params.ring_thickness_visual,
+        "sampling_step_deg" => params.sampling_step_deg,
+        "rpm" => params.rpm,
+        "cam_r0" => params.cam_r0,
+        "cam_k_per_unit" => params.cam_k_per_unit,
+        "center_distance_bias" => params.center_distance_bias,
+        "center_distance_scale" => params.center_distance_scale,
+        "arc_residual_tol_mm" => params.arc_residual_tol_mm,
+        "max_iter" => params.max_iter as f64,
+        "min_module" => params.min_module,
+        "min_clearance_target" => params.min_clearance_target,
+        "max_sliding_velocity" => unset(params.max_sliding_velocity)?,
+        "mean_piston_speed_mps" => unset(params.mean_piston_speed_mps)?,
+        "nvh_amp_limit" => unset(params.nvh_amp_limit)?,
+        "build_time_budget_ms" => unset(params.build_time_budget_ms)?,
+        "accel_limit" => unset(params.accel_limit)?,
+        "jerk_limit" => unset(params.jerk_limit)?,
+        "nvh_cycles" => params.nvh_cycles as f64,
+        "phi_refine_steps" => params.phi_refine_steps as f64,
+        _ => return Err(format!("unknown or non-numeric sweep parameter: {}", key)),
+    })
+}
+
+/// Build the tables at every point of the Cartesian product of `grid` (in parallel) and return
+/// each point's parameters with its diagnostics.
+///
+/// Each grid entry names a numeric [`LitvinParameters`] field (snake_case, as in the JNI map)
+/// and the values to sweep; the first entry varies slowest. Only diagnostics are kept, so memory
+/// stays bounded by the grid size. A point whose key is unknown, or whose build fails, carries
+/// the error in place of diagnostics. `rpm` cannot be swept while `mean_piston_speed_mps` is set.
+pub fn sweep_litvin(base: &LitvinParameters, grid: &[(&str, Vec<f64>)]) -> Vec<(LitvinParameters, FEAResult<Diagnostics>)> {
+    let mut points: Vec<(LitvinParameters, Result<(), String>)> = vec![(base.clone(), Ok(()))];
+    for (key, values) in grid {
+        points = points
+            .into_iter()
+            .flat_map(|(p, status)| {
+                values.iter().map(move |&v| {
+                    let mut q = p.clone();
+                    let status = status.clone().and_then(|_| set_numeric_param(&mut q, key, v));
+                    (q, status)
+                })
+            })
+            .collect();
+    }
+    maybe_into_par_iter!(points)
+        .map(|(p, status)| {
+            let result = status
+                .map_err(FEAError::ParameterValidation)
+                .and_then(|_| build_litvin_tables(&p))
+                .map(|tables| tables.diagnostics);
+            (p, result)
+        })
+        .collect()
+}
+
+/// Change of the headline diagnostics (perturbed − base) in [`SensitivityResult`].
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct DiagnosticChange {
+    pub clearance_min: f64,           // mm
+    pub jerk_max: f64,                // mm/s³
+    pub arc_length_residual_max: f64, // mm
+}
+
+/// Diagnostic response to perturbing one parameter by ±`delta`, from [`sensitivity_study`].
+#[derive(Debug)]
+pub struct SensitivityResult {
+    pub parameter: String,
+    pub delta: f64,
+    pub plus: FEAResult<DiagnosticChange>,  // parameter + delta
+    pub minus: FEAResult<DiagnosticChange>, // parameter − delta
+}
+
+/// Perturb each named parameter of `base` by ±delta, rebuild (in parallel) and report how far
+/// `clearance_min`, `jerk_max` and `arc_length_residual_max` move from the base build.
+///
+/// Keys are the snake_case numeric fields accepted by [`sweep_litvin`]; Option fields must be set
+/// in `base`. A side whose key is unknown, or whose base or perturbed build fails, carries the
+/// error. Results follow the order of `perturbations`.
+pub fn sensitivity_study(base: &LitvinParameters, perturbations: &[(&str, f64)]) -> Vec<SensitivityResult> {
+    let base_diag = build_litvin_tables(base).map(|tables| tables.diagnostics);
+    let jobs: Vec<(usize, f64)> = (0..perturbations.len()).flat_map(|i| [(i, 1.0), (i, -1.0)]).collect();
+    let changes: Vec<FEAResult<DiagnosticChange>> = maybe_into_par_iter!(jobs)
+        .map(|(i, sign)| {
+            let (key, delta) = perturbations[i];
+            let base_diag = base_diag
+                .as_ref()
+                .map_err(|e| FEAError::Calculation(format!("base build failed: {}", e)))?;
+            let mut p = base.clone();
+            numeric_param(base, key)
+                .and_then(|value| set_numeric_param(&mut p, key, value + sign * delta))
+                .map_err(FEAError::ParameterValidation)?;
+            let diag = build_litvin_tables(&p)?.diagnostics;
+            Ok(DiagnosticChange {
+                clearance_min: diag.clearance_min - base_diag.clearance_min,
+                jerk_max: diag.jerk_max - base_diag.jerk_max,
+                arc_length_residual_max: diag.arc_length_residual_max - base_diag.arc_length_residual_max,
+            })
+        })
+        .collect();
+    let mut sides = changes.into_iter();
+    perturbations
+        .iter()
+        .map(|&(key, delta)| SensitivityResult {
+            parameter: key.to_string(),
+            delta,
+            plus: sides.next().expect("one result per side"),
+            minus: sides.next().expect("one result per side"),
+        })
+        .collect()
+}
+
+/// Jerk maximum per ramp profile for `params`, in ascending order, checked against theory.
+///
+/// All profiles have the same ramp integral (½), so timing and cruise speed are shared and the
+/// ramp jerk scales with the peak |d³s/dt³| of the shape: Cycloidal π³/2 ≈ 15.5 < S7 52.5 < S5 60.
+/// Returns a `Calculation` error naming the first pair whose built `jerk_max` breaks that order.
+pub fn assert_profile_jerk_ordering(params: &LitvinParameters) -> FEAResult<Vec<(RampProfile, f64)>> {
+    let peak_d3s = |profile: RampProfile| -> f64 {
+        (0..=1000).map(|i| MotionProfiles::d3s(profile, i as f64 / 1000.0).abs()).fold(0.0, f64::max)
+    };
+    let mut expected = RampProfile::ALL;
+    expected.sort_by(|a, b| peak_d3s(*a).total_cmp(&peak_d3s(*b)));
+
+    let report = compare_ramp_profiles(params)?;
+    let jerk_of = |p: RampProfile| report.iter().find(|(q, _)| *q == p).map(|(_, d)| d.jerk_max).unwrap_or(f64::NAN);
+    let ordered: Vec<(RampProfile, f64)> = expected.iter().map(|&p| (p, jerk_of(p))).collect();
+    for pair in ordered.windows(2) {
+        let ((lo, lo_jerk), (hi, hi_jerk)) = (pair[0], pair[1]);
+        if lo_jerk.partial_cmp(&hi_jerk) != Some(std::cmp::Ordering::Less) {
+            return Err(FEAError::Calculation(format!(
+                "{:?} jerk_max {:.6e} is not below {:?} jerk_max {:.6e}",
+                lo, lo_jerk, hi, hi_jerk
+            )));
+        }
+    }
+    Ok(ordered)
+}
 
 #[cfg(test)]
 mod tests {
@@ -1035,6 +2261,89 @@ mod tests {
         }
     }
 
+    #[test]
+    fn violations_sorted_by_severity() {
+        let alpha: Vec<f64> = (0..12).map(|i| i as f64 * 10.0).collect();
+        // Shallow-but-long run, deep-but-short run, and a trailing run
+        let gaps = [1.0, -0.5, -0.5, -0.5, -0.5, 1.0, -3.0, 1.0, 1.0, 1.0, -0.2, -0.1];
+        let (min, v) = collect_violations(&alpha, &gaps, 6.0 * 1000.0);
+
+        assert_eq!(min, -3.0);
+        assert_eq!(v.len(), 3);
+        assert!(v.windows(2).all(|w| w[0].severity >= w[1].severity));
+        // Deep run: 3.0 mm over 10° beats 0.5 mm over 40°
+        assert_eq!(v[0].alpha_start_deg, 60.0);
+        assert_eq!(v[0].min_clearance, -3.0);
+        assert!((v[0].severity - 30.0).abs() < 1e-12);
+        // Each violation reports its own depth, not the global minimum
+        assert_eq!(v[1].min_clearance, -0.5);
+        assert_eq!(v[2].min_clearance, -0.2);
+        // At 1000 rpm α advances 6000 deg/s
+        assert!((v[0].time_start_s - 60.0 / 6000.0).abs() < 1e-15);
+    }
+
+    #[test]
+    fn find_phi_newton_refinement_reduces_arc_length_residual() {
+        // Smooth, convex arc length S(φ) on a coarse 10° grid
+        let step = 10.0;
+        let s_of = |phi_deg: f64| { let x = phi_deg.to_radians(); x * x + x };
+        let s_tab: Vec<f64> = (0..36).map(|i| s_of(i as f64 * step)).collect();
+        let residual = |refine: u32| -> f64 {
+            (1..60)
+                .map(|k| {
+                    let target = s_of(20.0 + k as f64 * 5.03);
+                    let (phi, clamped) = find_phi(target, &s_tab, 1.0, step, InterpOrder::Linear, refine);
+                    assert!(!clamped);
+                    (s_of(phi) - target).abs()
+                })
+                .fold(0.0, f64::max)
+        };
+        let (linear, refined) = (residual(0), residual(2));
+        assert!(refined < 0.1 * linear, "refined residual {} vs linear {}", refined, linear);
+        // Linear inversion is unchanged by default
+        let target = 0.5 * (s_tab[3] + s_tab[4]);
+        assert_eq!(find_phi(target, &s_tab, 1.0, step, InterpOrder::Linear, 0).0, 35.0);
+    }
+
+    #[test]
+    fn find_phi_out_of_range_target_is_clamped_and_noted() {
+        let step = 10.0;
+        let s_ring: Vec<f64> = (1..=36).map(|i| i as f64).collect();
+        let mut phi = vec![0.0; 3];
+        let mut notes = Vec::new();
+
+        // In-range targets produce no note
+        notes.extend(invert_arc_length(&[1.0, 10.5, 36.0], &s_ring, 1.0, step, InterpOrder::Linear, 0, &mut phi));
+        assert!(notes.is_empty(), "unexpected notes: {:?}", notes);
+        assert!((phi[1] - 95.0).abs() < 1e-12);
+
+        // Overshoot past the last sample (and below the first) is clamped and recorded
+        notes.extend(invert_arc_length(&[0.5, 10.5, 40.0], &s_ring, 1.0, step, InterpOrder::Linear, 0, &mut phi));
+        assert_eq!(phi[0], 0.0);
+        assert_eq!(phi[2], 35.0 * step);
+        assert_eq!(notes.len(), 1);
+        assert!(notes[0].contains("1 target(s) below and 1 above"), "note was {:?}", notes[0]);
+    }
+
+    #[test]
+    fn tooth_count_feasibility_flags_small_ring() {
+        let p = test_params();
+        let tables = build_litvin_tables(&p).expect("build_litvin_tables failed");
+        let d = &tables.diagnostics;
+        assert!(d.feasible_tooth_count >= MIN_RING_TEETH, "default ring fits only {} teeth", d.feasible_tooth_count);
+        assert!(!d.tooth_count_infeasible);
+
+        // A small ring relative to the module cannot carry enough teeth
+        let mut small = test_params();
+        small.cam_r0 = 10.0;
+        small.center_distance_bias = 15.0;
+        small.min_module = 3.0;
+        let tables = build_litvin_tables(&small).expect("build_litvin_tables failed");
+        let d = &tables.diagnostics;
+        assert!(d.feasible_tooth_count < MIN_RING_TEETH, "small ring fits {} teeth", d.feasible_tooth_count);
+        assert!(d.tooth_count_infeasible);
+    }
+
     #[test]
     fn gateb_transmission_from_phi_is_finite_positive_and_mean_near_one() {
         let p = test_params();
@@ -1062,4 +2371,35 @@ mod tests {
         // Expect mean close to 1.0 within a moderate tolerance
         assert!((mean - 1.0).abs() <= 0.2, "mean(i) not near 1.0: {}", mean);
     }
+
+    #[test]
+    fn ramp_profile_index_matches_all_and_full_integral() {
+        for (i, profile) in RampProfile::ALL.into_iter().enumerate() {
+            assert_eq!(profile.index(), i);
+            assert_eq!(MotionProfiles::full_integral(profile), MotionProfiles::integral(profile, 1.0));
+        }
+    }
+
+    #[test]
+    fn piston_at_time_matches_full_difference_tables() {
+        for interp_order in [InterpOrder::Linear, InterpOrder::Cubic] {
+            let p = LitvinParameters { interp_order, ..test_params() };
+            let tables = build_litvin_tables(&p).expect("build_litvin_tables failed");
+            let s = &tables.planets[0].piston_s;
+            let n = s.len();
+            let deg_per_sec = 6.0 * p.rpm;
+            let dt = p.sampling_step_deg / deg_per_sec;
+            let v: Vec<f64> = (0..n).map(|i| (s[(i + 1) % n] - s[(i + n - 1) % n]) / (2.0 * dt)).collect();
+            let a: Vec<f64> = (0..n).map(|i| (s[(i + 1) % n] - 2.0 * s[i] + s[(i + n - 1) % n]) / (dt * dt)).collect();
+            for k in 0..997 {
+                let t = k as f64 * 1.37e-4;
+                let alpha = (deg_per_sec * t).rem_euclid(360.0);
+                let at = |table: &[f64]| sample_uniform_table(table, alpha, p.sampling_step_deg, true, interp_order);
+                let (x, vel, acc) = tables.piston_at_time(0, t);
+                assert_eq!(x, at(s));
+                assert!((vel - at(&v)).abs() <= 1e-9 * at(&v).abs().max(1.0), "{:?} v at t={}", interp_order, t);
+                assert!((acc - at(&a)).abs() <= 1e-9 * at(&a).abs().max(1.0), "{:?} a at t={}", interp_order, t);
+            }
+        }
+    }
 }
@@ -63,11 +63,11 @@ pub struct LogRecord {
 
 impl LogRecord {
     /// Create a new log record
-    pub fn new<S: Into<String>>(
+    pub fn new<M: Into<String>, T: Into<String>, F: Into<String>>(
         level: LogLevel,
-        message: S,
-        target: S,
-        file: S,
+        message: M,
+        target: T,
+        file: F,
         line: u32,
     ) -> Self {
         let timestamp = SystemTime::now()
@@ -366,6 +366,17 @@ pub fn log(record: LogRecord) {
     }
 }
 
+/// Whether a record at `level` would be written by the global logger.
+/// False before the logger is initialized; use it to skip formatting expensive messages.
+pub fn enabled(level: LogLevel) -> bool {
+    if let Some(logger) = unsafe { (*std::ptr::addr_of!(LOGGER)).as_ref() } {
+        if let Ok(logger) = logger.lock() {
+            return level >= logger.min_level;
+        }
+    }
+    false
+}
+
 /// Flush the global logger
 pub fn flush() {
     if let Some(logger) = unsafe { LOGGER.as_ref() } {
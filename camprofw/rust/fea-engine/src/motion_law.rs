@@ -11,16 +11,126 @@
 //! - Parallel computation support via rayon
 //! - Real-time boundary condition calculation
 
+#[cfg(feature = "parallel")]
 use rayon::prelude::*;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::f64::consts::PI;
+use std::sync::Arc;
 use crate::error::{FEAError, FEAResult};
 
+/// Sign convention used for the fall-phase acceleration
+///
+/// The physical convention reports acceleration as the true second time derivative
+/// of displacement, so the follower decelerates (negative acceleration) at the start
+/// of the fall and accelerates back towards the base circle at its end.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum AccelSignConvention {
+    /// Acceleration is d²(displacement)/dt² in every phase
+    #[default]
+    Physical,
+    /// Legacy behaviour: the fall phase reuses the rise-phase `+sin` term
+    Legacy,
+}
+
+/// Shape of the modified-sine motion law used for the rise and fall phases
+///
+/// Both variants map the normalized phase angle β ∈ [0,1] to a normalized lift
+/// f(β) ∈ [0,1] with zero velocity at both ends.
+///
+/// - `Standard`: full-period form f = β − sin(2πβ)/(2π), with f'' = 2π·sin(2πβ)
+///   (peak normalized acceleration 2π ≈ 6.28).
+/// - `SplitRegion`: classic modified sine with quarter-wave regions of period 1/2
+///   for β ∈ [0,1/8] and [7/8,1], joined by a half-wave of period 3/2 over [1/8,7/8].
+///   With c = 1/(4+π):
+///   - β ∈ [0,1/8]:   f = c·(πβ − sin(4πβ)/4)
+///   - β ∈ [1/8,7/8]: f = c·(2 + πβ − 9·sin(π/3 + 4πβ/3)/4)
+///   - β ∈ [7/8,1]:   f = c·(4 + πβ − sin(4πβ)/4)
+///
+///   giving a peak normalized acceleration of 4π²c ≈ 5.53.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum ModifiedSineVariant {
+    /// Full-period `2π` form (historical behaviour)
+    #[default]
+    Standard,
+    /// Modified sine with distinct acceleration/deceleration regions
+    SplitRegion,
+}
+
+impl ModifiedSineVariant {
+    /// Normalized lift f(β)
+    #[inline]
+    pub fn shape(self, beta: f64) -> f64 {
+        match self {
+            ModifiedSineVariant::Standard => beta - (2.0 * PI * beta).sin() / (2.0 * PI),
+            ModifiedSineVariant::SplitRegion => {
+                let c = 1.0 / (4.0 + PI);
+                if beta <= 0.125 {
+                    c * (PI * beta - (4.0 * PI * beta).sin() / 4.0)
+                } else if beta <= 0.875 {
+                    c * (2.0 + PI * beta - 9.0 * (PI / 3.0 + 4.0 * PI * beta / 3.0).sin() / 4.0)
+                } else {
+                    c * (4.0 + PI * beta - (4.0 * PI * beta).sin() / 4.0)
+                }
+            }
+        }
+    }
+
+    /// First derivative df/dβ
+    #[inline]
+    pub fn shape_d1(self, beta: f64) -> f64 {
+        match self {
+            ModifiedSineVariant::Standard => 1.0 - (2.0 * PI * beta).cos(),
+            ModifiedSineVariant::SplitRegion => {
+                let c = 1.0 / (4.0 + PI);
+                if beta <= 0.125 || beta > 0.875 {
+                    c * PI * (1.0 - (4.0 * PI * beta).cos())
+                } else {
+                    c * PI * (1.0 - 3.0 * (PI / 3.0 + 4.0 * PI * beta / 3.0).cos())
+                }
+            }
+        }
+    }
+
+    /// Second derivative d²f/dβ²
+    #[inline]
+    pub fn shape_d2(self, beta: f64) -> f64 {
+        match self {
+            ModifiedSineVariant::Standard => 2.0 * PI * (2.0 * PI * beta).sin(),
+            ModifiedSineVariant::SplitRegion => {
+                let c = 1.0 / (4.0 + PI);
+                if beta <= 0.125 || beta > 0.875 {
+                    4.0 * PI * PI * c * (4.0 * PI * beta).sin()
+                } else {
+                    4.0 * PI * PI * c * (PI / 3.0 + 4.0 * PI * beta / 3.0).sin()
+                }
+            }
+        }
+    }
+
+    /// Third derivative d³f/dβ³
+    #[inline]
+    pub fn shape_d3(self, beta: f64) -> f64 {
+        match self {
+            ModifiedSineVariant::Standard => 4.0 * PI * PI * (2.0 * PI * beta).cos(),
+            ModifiedSineVariant::SplitRegion => {
+                let c = 1.0 / (4.0 + PI);
+                if beta <= 0.125 || beta > 0.875 {
+                    16.0 * PI * PI * PI * c * (4.0 * PI * beta).cos()
+                } else {
+                    16.0 * PI * PI * PI * c * (PI / 3.0 + 4.0 * PI * beta / 3.0).cos() / 3.0
+                }
+            }
+        }
+    }
+}
+
 /// Motion parameters for cam profile definition
 ///
 /// This struct is designed to be compatible with the Python MotionParameters
 /// and supports serialization/deserialization from TOML/JSON configuration files.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(deny_unknown_fields)]
 pub struct MotionParameters {
     /// Base circle radius in mm
     pub base_circle_radius: f64,
@@ -42,6 +152,23 @@ pub struct MotionParameters {
     pub velocity_limit: f64,
     /// Engine RPM
     pub rpm: f64,
+    /// Sign convention for the fall-phase acceleration
+    #[serde(default)]
+    pub accel_sign_convention: AccelSignConvention,
+    /// Modified-sine shape used for the rise and fall phases
+    #[serde(default)]
+    pub modified_sine_variant: ModifiedSineVariant,
+    /// Tolerance in degrees for phase boundary comparisons; angles within it of a
+    /// boundary are assigned to the earlier phase
+    #[serde(default = "default_phase_boundary_epsilon")]
+    pub phase_boundary_epsilon: f64,
+}
+
+/// Default tolerance for phase boundary comparisons in degrees
+pub const DEFAULT_PHASE_BOUNDARY_EPSILON: f64 = 1e-9;
+
+fn default_phase_boundary_epsilon() -> f64 {
+    DEFAULT_PHASE_BOUNDARY_EPSILON
 }
 
 impl Default for MotionParameters {
@@ -57,49 +184,86 @@ impl Default for MotionParameters {
             acceleration_limit: 500.0,
             velocity_limit: 100.0,
             rpm: 3000.0,
+            accel_sign_convention: AccelSignConvention::Physical,
+            modified_sine_variant: ModifiedSineVariant::Standard,
+            phase_boundary_epsilon: DEFAULT_PHASE_BOUNDARY_EPSILON,
         }
     }
 }
 
+/// Sparse set of [`MotionParameters`] overrides, e.g. only the fields a UI changed
+///
+/// Every field is optional; [`MotionParameters::merge`] applies the `Some` values
+/// onto a base. Missing JSON/TOML keys deserialize to `None`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct PartialMotionParameters {
+    pub base_circle_radius: Option<f64>,
+    pub max_lift: Option<f64>,
+    pub cam_duration: Option<f64>,
+    pub rise_duration: Option<f64>,
+    pub dwell_duration: Option<f64>,
+    pub fall_duration: Option<f64>,
+    pub jerk_limit: Option<f64>,
+    pub acceleration_limit: Option<f64>,
+    pub velocity_limit: Option<f64>,
+    pub rpm: Option<f64>,
+    pub accel_sign_convention: Option<AccelSignConvention>,
+    pub modified_sine_variant: Option<ModifiedSineVariant>,
+    pub phase_boundary_epsilon: Option<f64>,
+}
+
 impl MotionParameters {
     /// Validate motion parameters for physical feasibility
     pub fn validate(&self) -> FEAResult<()> {
+        match self.validate_all().into_iter().next() {
+            Some(err) => Err(err),
+            None => Ok(()),
+        }
+    }
+
+    /// Collect every validation failure, in the order [`validate`](Self::validate) checks them
+    pub fn validate_all(&self) -> Vec<FEAError> {
+        let mut errors = Vec::new();
         if self.max_lift <= 0.0 {
-            return Err(FEAError::ParameterValidation("Maximum lift must be positive".to_string()));
+            errors.push(FEAError::ParameterValidation("Maximum lift must be positive".to_string()));
         }
         if self.base_circle_radius <= 0.0 {
-            return Err(FEAError::ParameterValidation("Base circle radius must be positive".to_string()));
+            errors.push(FEAError::ParameterValidation("Base circle radius must be positive".to_string()));
         }
         if self.total_duration() <= 0.0 {
-            return Err(FEAError::ParameterValidation("Total cam duration must be positive".to_string()));
+            errors.push(FEAError::ParameterValidation("Total cam duration must be positive".to_string()));
         }
         if self.rpm <= 0.0 {
-            return Err(FEAError::ParameterValidation("RPM must be positive".to_string()));
+            errors.push(FEAError::ParameterValidation("RPM must be positive".to_string()));
         }
         
         // Additional validation for more robust error handling
         if self.rise_duration < 0.0 {
-            return Err(FEAError::ParameterValidation("Rise duration cannot be negative".to_string()));
+            errors.push(FEAError::ParameterValidation("Rise duration cannot be negative".to_string()));
         }
         if self.dwell_duration < 0.0 {
-            return Err(FEAError::ParameterValidation("Dwell duration cannot be negative".to_string()));
+            errors.push(FEAError::ParameterValidation("Dwell duration cannot be negative".to_string()));
         }
         if self.fall_duration < 0.0 {
-            return Err(FEAError::ParameterValidation("Fall duration cannot be negative".to_string()));
+            errors.push(FEAError::ParameterValidation("Fall duration cannot be negative".to_string()));
         }
         
         // Validate limits
         if self.velocity_limit <= 0.0 {
-            return Err(FEAError::ParameterValidation("Velocity limit must be positive".to_string()));
+            errors.push(FEAError::ParameterValidation("Velocity limit must be positive".to_string()));
         }
         if self.acceleration_limit <= 0.0 {
-            return Err(FEAError::ParameterValidation("Acceleration limit must be positive".to_string()));
+            errors.push(FEAError::ParameterValidation("Acceleration limit must be positive".to_string()));
         }
         if self.jerk_limit <= 0.0 {
-            return Err(FEAError::ParameterValidation("Jerk limit must be positive".to_string()));
+            errors.push(FEAError::ParameterValidation("Jerk limit must be positive".to_string()));
+        }
+        if self.phase_boundary_epsilon < 0.0 {
+            errors.push(FEAError::ParameterValidation("Phase boundary epsilon cannot be negative".to_string()));
         }
         
-        Ok(())
+        errors
     }
 
     /// Calculate total cam duration
@@ -107,12 +271,151 @@ impl MotionParameters {
         self.rise_duration + self.dwell_duration + self.fall_duration
     }
 
+    /// Copy of `self` with every `Some` field of `overrides` applied
+    ///
+    /// The result is not validated; pass it to [`MotionLaw::new`] as usual.
+    pub fn merge(&self, overrides: &PartialMotionParameters) -> MotionParameters {
+        MotionParameters {
+            base_circle_radius: overrides.base_circle_radius.unwrap_or(self.base_circle_radius),
+            max_lift: overrides.max_lift.unwrap_or(self.max_lift),
+            cam_duration: overrides.cam_duration.unwrap_or(self.cam_duration),
+            rise_duration: overrides.rise_duration.unwrap_or(self.rise_duration),
+            dwell_duration: overrides.dwell_duration.unwrap_or(self.dwell_duration),
+            fall_duration: overrides.fall_duration.unwrap_or(self.fall_duration),
+            jerk_limit: overrides.jerk_limit.unwrap_or(self.jerk_limit),
+            acceleration_limit: overrides.acceleration_limit.unwrap_or(self.acceleration_limit),
+            velocity_limit: overrides.velocity_limit.unwrap_or(self.velocity_limit),
+            rpm: overrides.rpm.unwrap_or(self.rpm),
+            accel_sign_convention: overrides.accel_sign_convention.unwrap_or(self.accel_sign_convention),
+            modified_sine_variant: overrides.modified_sine_variant.unwrap_or(self.modified_sine_variant),
+            phase_boundary_epsilon: overrides.phase_boundary_epsilon.unwrap_or(self.phase_boundary_epsilon),
+        }
+    }
+
+    /// Geometrically and dynamically similar parameter set
+    ///
+    /// Lengths (base circle radius, lift) are multiplied by `length_scale` and the cycle
+    /// time by `time_scale` (RPM divided by it), so the velocity, acceleration and jerk
+    /// limits scale as L/T, L/T² and L/T³. Phase durations are angles and stay unchanged,
+    /// which keeps the dimensionless shape s(θ)/max_lift identical.
+    pub fn scaled(&self, length_scale: f64, time_scale: f64) -> MotionParameters {
+        MotionParameters {
+            base_circle_radius: self.base_circle_radius * length_scale,
+            max_lift: self.max_lift * length_scale,
+            rpm: self.rpm / time_scale,
+            velocity_limit: self.velocity_limit * length_scale / time_scale,
+            acceleration_limit: self.acceleration_limit * length_scale / time_scale.powi(2),
+            jerk_limit: self.jerk_limit * length_scale / time_scale.powi(3),
+            ..self.clone()
+        }
+    }
+
+    /// Read parameters from `{prefix}_{FIELD}` environment variables, e.g. `CAMPRO_MAX_LIFT`
+    ///
+    /// Each field name is upper-cased after the prefix; unset variables keep the
+    /// [`Default`] value. The enum fields accept the same names as the JNI bridge
+    /// (`physical`/`legacy`, `standard`/`split_region`, case-insensitive). A value
+    /// that does not parse is a `ParameterValidation` error naming the variable, and
+    /// the result is validated like [`MotionLaw::new`] would.
+    pub fn from_env_with_defaults(prefix: &str) -> FEAResult<MotionParameters> {
+        let var = |field: &str| -> Option<(String, String)> {
+            let name = format!("{}_{}", prefix, field.to_uppercase());
+            std::env::var(&name).ok().map(|value| (name, value))
+        };
+        let number = |field: &str, default: f64| -> FEAResult<f64> {
+            match var(field) {
+                Some((name, value)) => value.trim().parse::<f64>().map_err(|_| {
+                    FEAError::ParameterValidation(format!("{} must be a number, got {:?}", name, value))
+                }),
+                None => Ok(default),
+            }
+        };
+        let def = MotionParameters::default();
+
+        let accel_sign_convention = match var("accel_sign_convention") {
+            Some((name, value)) => match value.trim().to_lowercase().as_str() {
+                "physical" => AccelSignConvention::Physical,
+                "legacy" => AccelSignConvention::Legacy,
+                _ => return Err(FEAError::ParameterValidation(format!("{} must be physical or legacy, got {:?}", name, value))),
+            },
+            None => def.accel_sign_convention,
+        };
+        let modified_sine_variant = match var("modified_sine_variant") {
+            Some((name, value)) => match value.trim().to_lowercase().as_str() {
+                "standard" => ModifiedSineVariant::Standard,
+                "split_region" | "splitregion" => ModifiedSineVariant::SplitRegion,
+                _ => return Err(FEAError::ParameterValidation(format!("{} must be standard or split_region, got {:?}", name, value))),
+            },
+            None => def.modified_sine_variant,
+        };
+
+        let params = MotionParameters {
+            base_circle_radius: number("base_circle_radius", def.base_circle_radius)?,
+            max_lift: number("max_lift", def.max_lift)?,
+            cam_duration: number("cam_duration", def.cam_duration)?,
+            rise_duration: number("rise_duration", def.rise_duration)?,
+            dwell_duration: number("dwell_duration", def.dwell_duration)?,
+            fall_duration: number("fall_duration", def.fall_duration)?,
+            jerk_limit: number("jerk_limit", def.jerk_limit)?,
+            acceleration_limit: number("acceleration_limit", def.acceleration_limit)?,
+            velocity_limit: number("velocity_limit", def.velocity_limit)?,
+            rpm: number("rpm", def.rpm)?,
+            accel_sign_convention,
+            modified_sine_variant,
+            phase_boundary_epsilon: number("phase_boundary_epsilon", def.phase_boundary_epsilon)?,
+        };
+        params.validate()?;
+        Ok(params)
+    }
+
     /// Calculate angular velocity in rad/s
     pub fn omega(&self) -> f64 {
         2.0 * PI * self.rpm / 60.0
     }
 }
 
+/// Number of analysis points used to find kinematic peaks in [`MotionLaw::retime_to_limits`]
+pub const RETIME_SAMPLES: usize = 3601;
+
+/// Default point cap for [`MotionLaw::analyze_kinematics_checked`] (≈400 MB of arrays)
+pub const DEFAULT_MAX_ANALYSIS_POINTS: usize = 10_000_000;
+
+/// Pitch-curve samples per revolution simplified by [`MotionLaw::profile_polyline`]
+pub const PROFILE_POLYLINE_SAMPLES: usize = 7200;
+
+/// Distance (mm) from `p` to the segment `a`–`b`
+fn point_segment_distance(p: (f64, f64), a: (f64, f64), b: (f64, f64)) -> f64 {
+    let (dx, dy) = (b.0 - a.0, b.1 - a.1);
+    let len2 = dx * dx + dy * dy;
+    let t = if len2 > 0.0 { (((p.0 - a.0) * dx + (p.1 - a.1) * dy) / len2).clamp(0.0, 1.0) } else { 0.0 };
+    ((p.0 - a.0 - t * dx).powi(2) + (p.1 - a.1 - t * dy).powi(2)).sqrt()
+}
+
+/// Chunk length of the deterministic reductions behind the [`KinematicAnalysis`] statistics
+const REDUCTION_CHUNK: usize = 16_384;
+
+/// Largest |value|, reduced over fixed chunks in parallel
+///
+/// `max` is exact and order-independent, so this matches a sequential fold bit for bit.
+fn reduce_max_abs(values: &[f64]) -> f64 {
+    let partials: Vec<f64> = maybe_par_chunks!(values, REDUCTION_CHUNK)
+        .map(|chunk| chunk.iter().map(|v| v.abs()).fold(0.0, f64::max))
+        .collect();
+    partials.into_iter().fold(0.0, f64::max)
+}
+
+/// Sum of `term(value)` as a fixed-shape tree: each [`REDUCTION_CHUNK`] chunk is summed left
+/// to right (chunks in parallel), then the partials are summed in chunk order
+///
+/// The grouping depends only on the length, so the result is bit-identical across runs,
+/// thread counts and with or without the `parallel` feature.
+fn reduce_sum(values: &[f64], term: impl Fn(f64) -> f64 + Sync) -> f64 {
+    let partials: Vec<f64> = maybe_par_chunks!(values, REDUCTION_CHUNK)
+        .map(|chunk| chunk.iter().map(|&v| term(v)).sum::<f64>())
+        .collect();
+    partials.into_iter().sum()
+}
+
 /// Kinematic analysis results
 #[derive(Debug, Clone, serde::Serialize)]
 pub struct KinematicAnalysis {
@@ -126,11 +429,295 @@ pub struct KinematicAnalysis {
     pub max_jerk: f64,
     pub rms_acceleration: f64,
     pub rms_jerk: f64,
+    pub rms_velocity: f64,
+    pub mean_displacement: f64,
     pub velocity_violation: bool,
     pub acceleration_violation: bool,
     pub jerk_violation: bool,
 }
 
+/// Column names of [`KinematicAnalysis::as_columns`], in channel order
+pub const KINEMATIC_COLUMNS: [&str; 5] = ["theta", "displacement", "velocity", "acceleration", "jerk"];
+
+/// Significant digits kept per value by [`KinematicAnalysis::content_hash`]
+pub const CONTENT_HASH_SIGNIFICANT_DIGITS: usize = 10;
+
+impl KinematicAnalysis {
+    /// Borrow the per-angle channels as named contiguous columns (see [`KINEMATIC_COLUMNS`])
+    ///
+    /// No data is copied; the slices can be handed to FFI consumers such as Arrow directly.
+    pub fn as_columns(&self) -> HashMap<&'static str, &[f64]> {
+        let channels = [&self.theta, &self.displacement, &self.velocity, &self.acceleration, &self.jerk];
+        KINEMATIC_COLUMNS.into_iter().zip(channels.map(Vec::as_slice)).collect()
+    }
+
+    /// Stable hash of the analysis for regression snapshots
+    ///
+    /// Every channel value (`theta`, displacement, velocity, acceleration, jerk) is
+    /// rounded to [`CONTENT_HASH_SIGNIFICANT_DIGITS`] significant digits by formatting it
+    /// in scientific notation, so last-bit floating-point noise does not change the
+    /// hash. Negative zero is folded into zero. The formatted values are hashed with
+    /// 64-bit FNV-1a, which is independent of platform and Rust version, and returned
+    /// as 16 lowercase hex digits. Derived statistics are not hashed; they follow from
+    /// the channels.
+    pub fn content_hash(&self) -> String {
+        const FNV_OFFSET: u64 = 0xcbf2_9ce4_8422_2325;
+        const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+        let mut hash = FNV_OFFSET;
+        let mut feed = |bytes: &[u8]| {
+            for &b in bytes {
+                hash ^= b as u64;
+                hash = hash.wrapping_mul(FNV_PRIME);
+            }
+        };
+        let channels = [&self.theta, &self.displacement, &self.velocity, &self.acceleration, &self.jerk];
+        for channel in channels {
+            feed(&(channel.len() as u64).to_le_bytes());
+            for &v in channel.iter() {
+                let v = if v == 0.0 { 0.0 } else { v };
+                feed(format!("{:.*e};", CONTENT_HASH_SIGNIFICANT_DIGITS - 1, v).as_bytes());
+            }
+        }
+        format!("{:016x}", hash)
+    }
+
+    /// Decimate the channels to at most `max_points` samples for plotting
+    ///
+    /// The samples are split into `max_points / 8` contiguous buckets and each bucket
+    /// keeps the rows holding the minimum and maximum of displacement, velocity,
+    /// acceleration and jerk (deduplicated, in angle order), so no channel loses its
+    /// peaks the way stride sampling would. At least one bucket is used, so the result
+    /// may hold up to 8 rows when `max_points < 8`. The summary statistics and
+    /// violation flags are copied unchanged; they describe the full-resolution run.
+    pub fn downsample(&self, max_points: usize) -> KinematicAnalysis {
+        let n = self.theta.len();
+        if n <= max_points {
+            return self.clone();
+        }
+        let buckets = (max_points / 8).max(1);
+        let channels = [&self.displacement, &self.velocity, &self.acceleration, &self.jerk];
+        let mut keep = Vec::with_capacity(buckets * 8);
+        for b in 0..buckets {
+            let (lo, hi) = (b * n / buckets, (b + 1) * n / buckets);
+            let start = keep.len();
+            for channel in channels {
+                let bucket = &channel[lo..hi];
+                let arg = |better: fn(f64, f64) -> bool| {
+                    (0..bucket.len()).fold(0, |best, i| if better(bucket[i], bucket[best]) { i } else { best })
+                };
+                keep.push(lo + arg(|a, b| a < b));
+                keep.push(lo + arg(|a, b| a > b));
+            }
+            keep[start..].sort_unstable();
+        }
+        keep.dedup();
+
+        let pick = |channel: &[f64]| keep.iter().map(|&i| channel[i]).collect::<Vec<f64>>();
+        KinematicAnalysis {
+            theta: pick(&self.theta),
+            displacement: pick(&self.displacement),
+            velocity: pick(&self.velocity),
+            acceleration: pick(&self.acceleration),
+            jerk: pick(&self.jerk),
+            ..self.clone()
+        }
+    }
+}
+
+impl std::fmt::Display for KinematicAnalysis {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "max_velocity={:.3} mm/s max_acceleration={:.3} mm/s^2 max_jerk={:.3} mm/s^3 \
+             rms_velocity={:.3} rms_acceleration={:.3} rms_jerk={:.3} \
+             velocity_violation={} acceleration_violation={} jerk_violation={}",
+            self.max_velocity,
+            self.max_acceleration,
+            self.max_jerk,
+            self.rms_velocity,
+            self.rms_acceleration,
+            self.rms_jerk,
+            self.velocity_violation,
+            self.acceleration_violation,
+            self.jerk_violation
+        )
+    }
+}
+
+/// Peak kinematics as a percentage of the configured limits
+#[derive(Debug, Clone, Copy, PartialEq, serde::Serialize)]
+pub struct LimitUtilization {
+    /// `max_velocity / velocity_limit * 100`
+    pub velocity_pct: f64,
+    /// `max_acceleration / acceleration_limit * 100`
+    pub acceleration_pct: f64,
+    /// `max_jerk / jerk_limit * 100`
+    pub jerk_pct: f64,
+}
+
+/// Displacement, velocity, acceleration and jerk at a single cam angle
+#[derive(Debug, Clone, Copy, PartialEq, serde::Serialize)]
+pub struct MotionSample {
+    /// Displacement in mm
+    pub displacement: f64,
+    /// Velocity in mm/s
+    pub velocity: f64,
+    /// Acceleration in mm/s²
+    pub acceleration: f64,
+    /// Jerk in mm/s³
+    pub jerk: f64,
+}
+
+/// Per-sample kinematic arrays produced by [`MotionLaw::evaluate_grid`]
+#[derive(Debug, Clone, Default, PartialEq, serde::Serialize)]
+pub struct KinematicArrays {
+    /// Displacement in mm
+    pub displacement: Vec<f64>,
+    /// Velocity in mm/s
+    pub velocity: Vec<f64>,
+    /// Acceleration in mm/s²
+    pub acceleration: Vec<f64>,
+    /// Jerk in mm/s³
+    pub jerk: Vec<f64>,
+}
+
+/// Motion phase an angle falls into
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Phase {
+    Rise,
+    Dwell,
+    Fall,
+    Outside,
+    /// Inside the range of a sampled law; `beta` carries the normalized angle in degrees
+    Sampled,
+}
+
+/// Finite-difference stencil used to differentiate the lift samples of a sampled law
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum DiffStencil {
+    /// Three neighbouring samples, second-order accurate
+    #[default]
+    ThreePoint,
+    /// Five neighbouring samples, fourth-order accurate on smooth data
+    FivePoint,
+}
+
+impl DiffStencil {
+    /// Samples on each side of the centre node
+    fn half_width(self) -> usize {
+        match self {
+            DiffStencil::ThreePoint => 1,
+            DiffStencil::FivePoint => 2,
+        }
+    }
+}
+
+/// Externally computed lift samples with angle derivatives precomputed at the nodes
+#[derive(Debug, Clone)]
+struct SampledProfile {
+    theta: Vec<f64>,
+    /// Lift (mm) and its first three derivatives w.r.t. angle (mm/deg^k), indexed by order
+    channels: [Vec<f64>; 4],
+}
+
+impl SampledProfile {
+    fn new(theta: Vec<f64>, lift: Vec<f64>, stencil: DiffStencil) -> Self {
+        let hw = stencil.half_width();
+        let d1 = stencil_derivative(&theta, &lift, hw);
+        let d2 = stencil_derivative(&theta, &d1, hw);
+        let d3 = stencil_derivative(&theta, &d2, hw);
+        Self { theta, channels: [lift, d1, d2, d3] }
+    }
+
+    /// Linear interpolation of derivative `order` at `theta`, which must lie within the samples
+    fn interpolate(&self, order: usize, theta: f64) -> f64 {
+        let values = &self.channels[order];
+        let n = self.theta.len();
+        let i = self.theta.partition_point(|&t| t <= theta).clamp(1, n - 1);
+        let (t0, t1) = (self.theta[i - 1], self.theta[i]);
+        let w = ((theta - t0) / (t1 - t0)).clamp(0.0, 1.0);
+        values[i - 1] + (values[i] - values[i - 1]) * w
+    }
+}
+
+/// First derivative of `y(x)` at every node from the Lagrange polynomial through the
+/// `2 * half_width + 1` nearest nodes; the stencil shifts inwards at the ends, so
+/// non-uniform spacing and the boundaries need no special cases.
+fn stencil_derivative(x: &[f64], y: &[f64], half_width: usize) -> Vec<f64> {
+    let n = x.len();
+    let m = (2 * half_width + 1).min(n);
+    (0..n)
+        .map(|i| {
+            let start = i.saturating_sub(half_width).min(n - m);
+            let nodes = start..start + m;
+            nodes
+                .clone()
+                .map(|j| {
+                    // d/dx of the Lagrange basis polynomial L_j, evaluated at x_i
+                    let weight = if j == i {
+                        nodes.clone().filter(|&k| k != i).map(|k| 1.0 / (x[i] - x[k])).sum::<f64>()
+                    } else {
+                        let num: f64 = nodes.clone().filter(|&k| k != i && k != j).map(|k| x[i] - x[k]).product();
+                        let den: f64 = nodes.clone().filter(|&k| k != j).map(|k| x[j] - x[k]).product();
+                        num / den
+                    };
+                    weight * y[j]
+                })
+                .sum()
+        })
+        .collect()
+}
+
+/// Angle grid with phase classification and normalized phase position `beta` cached
+///
+/// Built by [`MotionLaw::precompute_grid`]. The classification depends only on the
+/// rise/dwell/fall durations, so a grid can be reused with any law sharing those
+/// durations (e.g. the same profile retimed to a different RPM).
+#[derive(Debug, Clone)]
+pub struct PrecomputedGrid {
+    angles: Vec<f64>,
+    phases: Vec<(Phase, f64)>,
+    durations: (f64, f64, f64),
+}
+
+impl PrecomputedGrid {
+    /// Angles the grid was built from, in degrees
+    pub fn angles(&self) -> &[f64] {
+        &self.angles
+    }
+
+    /// Number of grid points
+    pub fn len(&self) -> usize {
+        self.angles.len()
+    }
+
+    /// Whether the grid has no points
+    pub fn is_empty(&self) -> bool {
+        self.angles.is_empty()
+    }
+}
+
+/// Serializable snapshot of a built [`MotionLaw`]
+///
+/// Captures the parameters together with the derived constants so a built law can be
+/// persisted and restored across processes. Restoring via [`MotionLaw::from_snapshot`]
+/// re-validates the parameters and checks the stored constants against them.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MotionLawSnapshot {
+    /// Motion parameters the law was built from
+    pub params: MotionParameters,
+    /// Angular velocity in rad/s
+    pub omega: f64,
+    /// Total cam duration in degrees
+    pub total_duration: f64,
+    /// Cam angular rate dθ/dt in deg/s
+    pub omega_deg: f64,
+    /// `omega_deg²`
+    pub omega_deg2: f64,
+    /// `omega_deg³`
+    pub omega_deg3: f64,
+}
+
 /// High-performance motion law implementation
 ///
 /// This struct provides the core motion law calculations optimized for FEA simulation.
@@ -141,7 +728,14 @@ pub struct MotionLaw {
     params: MotionParameters,
     omega: f64,
     total_duration: f64,
-    deg_to_rad: f64,
+    /// Cam angular rate dθ/dt in deg/s
+    omega_deg: f64,
+    /// `omega_deg²`, cached for acceleration
+    omega_deg2: f64,
+    /// `omega_deg³`, cached for jerk
+    omega_deg3: f64,
+    /// Lift samples when built by [`MotionLaw::from_samples`]
+    samples: Option<Arc<SampledProfile>>,
 }
 
 impl MotionLaw {
@@ -150,173 +744,492 @@ impl MotionLaw {
         // Validate parameters
         parameters.validate()?;
 
+        let motion_law = Self::from_parameters_unchecked(parameters);
+
+        // Perform additional validation
+        if motion_law.total_duration > 360.0 {
+            return Err(FEAError::ParameterValidation(
+                "Total cam duration cannot exceed 360 degrees".to_string()
+            ));
+        }
+
+        Ok(motion_law)
+    }
+
+    /// Create a best-effort motion law, reporting soft issues as warnings
+    ///
+    /// Only physically impossible parameters are rejected: non-positive lift, base
+    /// circle radius, rise duration or RPM, negative dwell/fall durations or boundary
+    /// epsilon, and a total duration over 360°. Non-positive limits and peaks that
+    /// exceed the velocity, acceleration or jerk limits (over [`RETIME_SAMPLES`]
+    /// points) become warnings, so the law can still be visualized.
+    pub fn new_lenient(parameters: MotionParameters) -> FEAResult<(Self, Vec<String>)> {
+        let p = &parameters;
+        let hard = [
+            (p.max_lift <= 0.0, "Maximum lift must be positive"),
+            (p.base_circle_radius <= 0.0, "Base circle radius must be positive"),
+            (p.rise_duration <= 0.0, "Rise duration must be positive"),
+            (p.rpm <= 0.0, "RPM must be positive"),
+            (p.dwell_duration < 0.0, "Dwell duration cannot be negative"),
+            (p.fall_duration < 0.0, "Fall duration cannot be negative"),
+            (p.phase_boundary_epsilon < 0.0, "Phase boundary epsilon cannot be negative"),
+            (p.total_duration() > 360.0, "Total cam duration cannot exceed 360 degrees"),
+        ];
+        if let Some((_, msg)) = hard.iter().find(|(bad, _)| *bad) {
+            return Err(FEAError::ParameterValidation(msg.to_string()));
+        }
+
+        // Everything validate_all still reports here is a limit problem
+        let mut warnings: Vec<String> = parameters
+            .validate_all()
+            .into_iter()
+            .map(|e| match e {
+                FEAError::ParameterValidation(msg) => msg,
+                other => other.to_string(),
+            })
+            .collect();
+        let motion_law = Self::from_parameters_unchecked(parameters);
+        let analysis = motion_law.analyze_kinematics(RETIME_SAMPLES);
+        let p = &motion_law.params;
+        if analysis.velocity_violation {
+            warnings.push(format!("Peak velocity {:.3} mm/s exceeds the limit {:.3} mm/s", analysis.max_velocity, p.velocity_limit));
+        }
+        if analysis.acceleration_violation {
+            warnings.push(format!(
+                "Peak acceleration {:.3} mm/s² exceeds the limit {:.3} mm/s²",
+                analysis.max_acceleration, p.acceleration_limit
+            ));
+        }
+        if analysis.jerk_violation {
+            warnings.push(format!("Peak jerk {:.3} mm/s³ exceeds the limit {:.3} mm/s³", analysis.max_jerk, p.jerk_limit));
+        }
+        Ok((motion_law, warnings))
+    }
+
+    /// Build the law and its cached rates without validating `parameters`
+    fn from_parameters_unchecked(parameters: MotionParameters) -> Self {
         let omega = parameters.omega();
         let total_duration = parameters.total_duration();
         let deg_to_rad = PI / 180.0;
 
+        // Precompute angular-rate powers used by the hot derivative methods
+        let omega_deg = omega / deg_to_rad;
+        let omega_deg2 = omega_deg * omega_deg;
+        let omega_deg3 = omega_deg2 * omega_deg;
+
         // Create the motion law
-        let motion_law = Self {
+        Self {
             params: parameters,
             omega,
             total_duration,
-            deg_to_rad,
-        };
+            omega_deg,
+            omega_deg2,
+            omega_deg3,
+            samples: None,
+        }
+    }
 
-        // Perform additional validation
-        if motion_law.total_duration > 360.0 {
-            return Err(FEAError::ParameterValidation(
-                "Total cam duration cannot exceed 360 degrees".to_string()
-            ));
+    /// Create a motion law from an externally computed lift profile
+    ///
+    /// `theta` (deg, strictly increasing within [0, 360]) and `lift` (mm) must have the
+    /// same length, at least 3. Lift is linearly interpolated between samples; velocity,
+    /// acceleration and jerk come from finite differences of the samples w.r.t. angle,
+    /// scaled by the cam rate for `rpm`. Angles outside the sampled range give zero.
+    ///
+    /// [`parameters`](Self::parameters) reports the peak lift as `max_lift` and the last
+    /// sample angle as the cam duration (all rise), with default limits. Snapshots record
+    /// only those parameters, not the samples.
+    pub fn from_samples(theta: Vec<f64>, lift: Vec<f64>, rpm: f64) -> FEAResult<Self> {
+        Self::from_samples_with_stencil(theta, lift, rpm, DiffStencil::default())
+    }
+
+    /// [`from_samples`](Self::from_samples) with a chosen finite-difference stencil
+    pub fn from_samples_with_stencil(theta: Vec<f64>, lift: Vec<f64>, rpm: f64, stencil: DiffStencil) -> FEAResult<Self> {
+        if theta.len() != lift.len() {
+            return Err(FEAError::ParameterValidation(format!(
+                "theta and lift must have the same length ({} vs {})",
+                theta.len(),
+                lift.len()
+            )));
+        }
+        if theta.len() < 3 {
+            return Err(FEAError::ParameterValidation("At least 3 samples are required".to_string()));
+        }
+        if theta.iter().chain(lift.iter()).any(|v| !v.is_finite()) {
+            return Err(FEAError::ParameterValidation("Samples must be finite".to_string()));
+        }
+        if let Some(i) = theta.windows(2).position(|w| w[1] <= w[0]) {
+            return Err(FEAError::ParameterValidation(format!(
+                "theta must be strictly increasing (sample {} is {} after {})",
+                i + 1,
+                theta[i + 1],
+                theta[i]
+            )));
+        }
+        let last = theta[theta.len() - 1];
+        if theta[0] < 0.0 || last > 360.0 {
+            return Err(FEAError::ParameterValidation("theta must lie within [0, 360] degrees".to_string()));
         }
 
+        let params = MotionParameters {
+            max_lift: lift.iter().fold(0.0_f64, |m, l| m.max(l.abs())),
+            cam_duration: last,
+            rise_duration: last,
+            dwell_duration: 0.0,
+            fall_duration: 0.0,
+            rpm,
+            ..MotionParameters::default()
+        };
+        let mut motion_law = Self::new(params)?;
+        motion_law.samples = Some(Arc::new(SampledProfile::new(theta, lift, stencil)));
         Ok(motion_law)
     }
 
+    /// Derivative `order` of a sampled law's lift at a classified angle (0 for analytic laws)
+    #[inline]
+    fn sampled(&self, order: usize, theta: f64) -> f64 {
+        self.samples.as_ref().map_or(0.0, |s| s.interpolate(order, theta))
+    }
+
     /// Get motion parameters
     pub fn parameters(&self) -> &MotionParameters {
         &self.params
     }
 
+    /// Capture the parameters and derived constants as a serializable snapshot
+    pub fn snapshot(&self) -> MotionLawSnapshot {
+        MotionLawSnapshot {
+            params: self.params.clone(),
+            omega: self.omega,
+            total_duration: self.total_duration,
+            omega_deg: self.omega_deg,
+            omega_deg2: self.omega_deg2,
+            omega_deg3: self.omega_deg3,
+        }
+    }
+
+    /// Restore a motion law from a snapshot
+    ///
+    /// The parameters are re-validated and the derived constants are recomputed; a
+    /// snapshot whose stored constants disagree with its parameters is rejected.
+    pub fn from_snapshot(snapshot: MotionLawSnapshot) -> FEAResult<Self> {
+        let motion_law = Self::new(snapshot.params)?;
+
+        let derived = [
+            ("omega", snapshot.omega, motion_law.omega),
+            ("total_duration", snapshot.total_duration, motion_law.total_duration),
+            ("omega_deg", snapshot.omega_deg, motion_law.omega_deg),
+            ("omega_deg2", snapshot.omega_deg2, motion_law.omega_deg2),
+            ("omega_deg3", snapshot.omega_deg3, motion_law.omega_deg3),
+        ];
+        for (name, stored, expected) in derived {
+            if (stored - expected).abs() > 1e-12 * expected.abs().max(1.0) {
+                return Err(FEAError::Deserialization(format!(
+                    "Snapshot field {} = {} does not match parameters (expected {})",
+                    name, stored, expected
+                )));
+            }
+        }
+
+        Ok(motion_law)
+    }
+
+    /// Get the cam angular velocity in rad/s
+    pub fn omega(&self) -> f64 {
+        self.omega
+    }
+
     /// Calculate cam follower displacement for a single angle
     ///
     /// This is the performance-critical function that will be called millions of times
-    /// during FEA simulation. It uses the modified sine motion law for smooth acceleration.
+    /// during FEA simulation. It uses the modified sine motion law for smooth acceleration,
+    /// shaped according to [`MotionParameters::modified_sine_variant`].
+    ///
+    /// For analytic profiles the result stays within `[0, max_lift]` for θ in
+    /// `[0, 360]`: both shape functions have `f(0) = 0`, `f(1) = 1` and `f'(β) ≥ 0`
+    /// (e.g. `1 - cos(2πβ)` for the standard form), so the `sin(2πβ)` term bends the
+    /// curve but can never carry it past either end of the stroke.
     #[inline]
     pub fn displacement(&self, theta: f64) -> f64 {
-        let theta_norm = theta % 360.0;
-
-        if theta_norm <= self.params.rise_duration {
-            // Rise phase
-            let beta = theta_norm / self.params.rise_duration;
-            self.params.max_lift * (beta - (2.0 * PI * beta).sin() / (2.0 * PI))
-        } else if theta_norm <= self.params.rise_duration + self.params.dwell_duration {
-            // Dwell phase
-            self.params.max_lift
-        } else if theta_norm <= self.total_duration {
-            // Fall phase
-            let theta_fall = theta_norm - (self.params.rise_duration + self.params.dwell_duration);
-            let beta = theta_fall / self.params.fall_duration;
-            self.params.max_lift * (1.0 - (beta - (2.0 * PI * beta).sin() / (2.0 * PI)))
-        } else {
+        let (phase, beta) = self.classify(theta);
+        match phase {
+            Phase::Rise => self.params.max_lift * self.params.modified_sine_variant.shape(beta),
+            Phase::Dwell => self.params.max_lift,
+            Phase::Fall => self.params.max_lift * (1.0 - self.params.modified_sine_variant.shape(beta)),
+            Phase::Sampled => self.sampled(0, beta),
             // Outside cam duration
-            0.0
+            Phase::Outside => 0.0,
         }
     }
 
+    /// Displacement at `theta` as a fixed-point integer in units of 10^-`scale` mm
+    ///
+    /// Computes `displacement(theta) * 10^scale` and rounds to the nearest integer,
+    /// halfway cases away from zero (`f64::round`). Last-bit differences in the
+    /// platform's floating-point math vanish in the rounding unless the value sits
+    /// right at a half-unit, so the result suits hashing and golden tests. Values
+    /// beyond the `i64` range saturate; `scale` above 22 loses the exact power of ten.
+    pub fn displacement_fixed(&self, theta: f64, scale: u32) -> i64 {
+        (self.displacement(theta) * 10f64.powi(scale as i32)).round() as i64
+    }
+
     /// Calculate cam follower velocity for a single angle
+    ///
+    /// Derivatives with respect to time use the cam angular rate `dθ/dt` in deg/s,
+    /// since the phase angles are expressed in degrees. The rate and its powers are
+    /// precomputed in [`MotionLaw::new`].
     #[inline]
     pub fn velocity(&self, theta: f64) -> f64 {
-        let theta_norm = theta % 360.0;
-
-        if theta_norm <= self.params.rise_duration {
-            // Rise phase
-            let beta = theta_norm / self.params.rise_duration;
-            let dbeta_dtheta = 1.0 / self.params.rise_duration;
-            self.params.max_lift * dbeta_dtheta * (1.0 - (2.0 * PI * beta).cos()) * self.omega * self.deg_to_rad
-        } else if theta_norm <= self.params.rise_duration + self.params.dwell_duration {
-            // Dwell phase - velocity is zero
-            0.0
-        } else if theta_norm <= self.total_duration {
-            // Fall phase
-            let theta_fall = theta_norm - (self.params.rise_duration + self.params.dwell_duration);
-            let beta = theta_fall / self.params.fall_duration;
-            let dbeta_dtheta = 1.0 / self.params.fall_duration;
-            -self.params.max_lift * dbeta_dtheta * (1.0 - (2.0 * PI * beta).cos()) * self.omega * self.deg_to_rad
-        } else {
-            // Outside cam duration
-            0.0
+        let (phase, beta) = self.classify(theta);
+        match phase {
+            Phase::Rise => {
+                let dbeta_dtheta = 1.0 / self.params.rise_duration;
+                self.params.max_lift * dbeta_dtheta * self.params.modified_sine_variant.shape_d1(beta) * self.omega_deg
+            }
+            Phase::Fall => {
+                let dbeta_dtheta = 1.0 / self.params.fall_duration;
+                -self.params.max_lift * dbeta_dtheta * self.params.modified_sine_variant.shape_d1(beta) * self.omega_deg
+            }
+            Phase::Sampled => self.sampled(1, beta) * self.omega_deg,
+            // Dwell and outside cam duration - velocity is zero
+            Phase::Dwell | Phase::Outside => 0.0,
         }
     }
 
     /// Calculate cam follower acceleration for a single angle
+    ///
+    /// With [`AccelSignConvention::Physical`] this is the second derivative of
+    /// [`displacement`](Self::displacement): positive in the first half of the rise,
+    /// negative in the second half of the rise and the first half of the fall, and
+    /// positive again at the end of the fall.
     #[inline]
     pub fn acceleration(&self, theta: f64) -> f64 {
-        let theta_norm = theta % 360.0;
+        let (phase, beta) = self.classify(theta);
+        self.accel_sign(phase) * self.dd_theta_at(phase, beta) * self.omega_deg2
+    }
 
-        if theta_norm <= self.params.rise_duration {
-            // Rise phase
-            let beta = theta_norm / self.params.rise_duration;
-            let dbeta_dtheta = 1.0 / self.params.rise_duration;
-            self.params.max_lift * (dbeta_dtheta * dbeta_dtheta) * 2.0 * PI * (2.0 * PI * beta).sin() *
-                (self.omega * self.deg_to_rad) * (self.omega * self.deg_to_rad)
-        } else if theta_norm <= self.params.rise_duration + self.params.dwell_duration {
-            // Dwell phase - acceleration is zero
-            0.0
-        } else if theta_norm <= self.total_duration {
-            // Fall phase
-            let theta_fall = theta_norm - (self.params.rise_duration + self.params.dwell_duration);
-            let beta = theta_fall / self.params.fall_duration;
-            let dbeta_dtheta = 1.0 / self.params.fall_duration;
-            self.params.max_lift * (dbeta_dtheta * dbeta_dtheta) * 2.0 * PI * (2.0 * PI * beta).sin() *
-                (self.omega * self.deg_to_rad) * (self.omega * self.deg_to_rad)
-        } else {
-            // Outside cam duration
-            0.0
+    /// Second derivative of displacement with respect to cam angle, d²x/dθ² in mm/deg²
+    ///
+    /// Purely geometric: independent of RPM and always in the physical sign
+    /// convention, whatever `accel_sign_convention` is set to.
+    #[inline]
+    pub fn displacement_dd_theta(&self, theta: f64) -> f64 {
+        let (phase, beta) = self.classify(theta);
+        self.dd_theta_at(phase, beta)
+    }
+
+    /// d²x/dθ² at a classified phase position
+    #[inline]
+    fn dd_theta_at(&self, phase: Phase, beta: f64) -> f64 {
+        match phase {
+            Phase::Rise => {
+                let dbeta_dtheta = 1.0 / self.params.rise_duration;
+                self.params.max_lift * (dbeta_dtheta * dbeta_dtheta) * self.params.modified_sine_variant.shape_d2(beta)
+            }
+            Phase::Fall => {
+                let dbeta_dtheta = 1.0 / self.params.fall_duration;
+                -self.params.max_lift * (dbeta_dtheta * dbeta_dtheta) * self.params.modified_sine_variant.shape_d2(beta)
+            }
+            Phase::Sampled => self.sampled(2, beta),
+            // Dwell and outside cam duration - displacement is constant
+            Phase::Dwell | Phase::Outside => 0.0,
+        }
+    }
+
+    /// Sign applied to d²x/dθ² for the configured acceleration convention;
+    /// only the legacy convention flips the fall phase
+    #[inline]
+    fn accel_sign(&self, phase: Phase) -> f64 {
+        match (phase, self.params.accel_sign_convention) {
+            (Phase::Fall, AccelSignConvention::Legacy) => -1.0,
+            _ => 1.0,
         }
     }
 
     /// Calculate cam follower jerk for a single angle
     #[inline]
     pub fn jerk(&self, theta: f64) -> f64 {
-        let theta_norm = theta % 360.0;
+        let (phase, beta) = self.classify(theta);
+        match phase {
+            Phase::Rise => {
+                let dbeta_dtheta = 1.0 / self.params.rise_duration;
+                self.params.max_lift * (dbeta_dtheta * dbeta_dtheta * dbeta_dtheta) * self.params.modified_sine_variant.shape_d3(beta) * self.omega_deg3
+            }
+            Phase::Fall => {
+                let dbeta_dtheta = 1.0 / self.params.fall_duration;
+                -self.params.max_lift * (dbeta_dtheta * dbeta_dtheta * dbeta_dtheta) * self.params.modified_sine_variant.shape_d3(beta) * self.omega_deg3
+            }
+            Phase::Sampled => self.sampled(3, beta) * self.omega_deg3,
+            // Dwell and outside cam duration - jerk is zero
+            Phase::Dwell | Phase::Outside => 0.0,
+        }
+    }
 
-        if theta_norm <= self.params.rise_duration {
-            // Rise phase
-            let beta = theta_norm / self.params.rise_duration;
-            let dbeta_dtheta = 1.0 / self.params.rise_duration;
-            self.params.max_lift * (dbeta_dtheta * dbeta_dtheta * dbeta_dtheta) * 4.0 * PI * PI * (2.0 * PI * beta).cos() *
-                (self.omega * self.deg_to_rad) * (self.omega * self.deg_to_rad) * (self.omega * self.deg_to_rad)
-        } else if theta_norm <= self.params.rise_duration + self.params.dwell_duration {
-            // Dwell phase - jerk is zero
-            0.0
-        } else if theta_norm <= self.total_duration {
-            // Fall phase
-            let theta_fall = theta_norm - (self.params.rise_duration + self.params.dwell_duration);
-            let beta = theta_fall / self.params.fall_duration;
-            let dbeta_dtheta = 1.0 / self.params.fall_duration;
-            -self.params.max_lift * (dbeta_dtheta * dbeta_dtheta * dbeta_dtheta) * 4.0 * PI * PI * (2.0 * PI * beta).cos() *
-                (self.omega * self.deg_to_rad) * (self.omega * self.deg_to_rad) * (self.omega * self.deg_to_rad)
+    /// Calculate displacement, velocity, acceleration and jerk for a single angle
+    ///
+    /// Equivalent to calling the four channel methods, but normalizes `theta` and
+    /// computes the phase `beta` only once.
+    #[inline]
+    pub fn evaluate(&self, theta: f64) -> MotionSample {
+        let (phase, beta) = self.classify(theta);
+        self.sample_phase(phase, beta)
+    }
+
+    /// Phase and normalized position `beta` within that phase for an angle
+    ///
+    /// Shared by every channel so they agree on the phase of a boundary angle. An angle
+    /// within `phase_boundary_epsilon` past a boundary stays in the earlier phase, with
+    /// `beta` clamped to 1.
+    #[inline]
+    fn classify(&self, theta: f64) -> (Phase, f64) {
+        let theta_norm = theta % 360.0;
+        let eps = self.params.phase_boundary_epsilon;
+        if let Some(samples) = &self.samples {
+            let (first, last) = (samples.theta[0], samples.theta[samples.theta.len() - 1]);
+            return if theta_norm >= first - eps && theta_norm <= last + eps {
+                (Phase::Sampled, theta_norm.clamp(first, last))
+            } else {
+                (Phase::Outside, 0.0)
+            };
+        }
+        let rise_end = self.params.rise_duration;
+        let dwell_end = rise_end + self.params.dwell_duration;
+
+        if theta_norm <= rise_end + eps {
+            (Phase::Rise, (theta_norm / rise_end).min(1.0))
+        } else if theta_norm <= dwell_end + eps {
+            (Phase::Dwell, 0.0)
+        } else if theta_norm <= self.total_duration + eps {
+            let theta_fall = theta_norm - dwell_end;
+            (Phase::Fall, (theta_fall / self.params.fall_duration).min(1.0))
         } else {
-            // Outside cam duration
-            0.0
+            (Phase::Outside, 0.0)
         }
     }
 
+    /// Kinematics at a classified phase position
+    #[inline]
+    fn sample_phase(&self, phase: Phase, beta: f64) -> MotionSample {
+        let variant = self.params.modified_sine_variant;
+        let lift = self.params.max_lift;
+
+        match phase {
+            Phase::Rise => {
+                let dbeta_dtheta = 1.0 / self.params.rise_duration;
+                MotionSample {
+                    displacement: lift * variant.shape(beta),
+                    velocity: lift * dbeta_dtheta * variant.shape_d1(beta) * self.omega_deg,
+                    acceleration: self.dd_theta_at(phase, beta) * self.omega_deg2,
+                    jerk: lift * (dbeta_dtheta * dbeta_dtheta * dbeta_dtheta) * variant.shape_d3(beta) * self.omega_deg3,
+                }
+            }
+            Phase::Dwell => MotionSample { displacement: lift, velocity: 0.0, acceleration: 0.0, jerk: 0.0 },
+            Phase::Fall => {
+                let dbeta_dtheta = 1.0 / self.params.fall_duration;
+                MotionSample {
+                    displacement: lift * (1.0 - variant.shape(beta)),
+                    velocity: -lift * dbeta_dtheta * variant.shape_d1(beta) * self.omega_deg,
+                    acceleration: self.accel_sign(phase) * self.dd_theta_at(phase, beta) * self.omega_deg2,
+                    jerk: -lift * (dbeta_dtheta * dbeta_dtheta * dbeta_dtheta) * variant.shape_d3(beta) * self.omega_deg3,
+                }
+            }
+            Phase::Sampled => MotionSample {
+                displacement: self.sampled(0, beta),
+                velocity: self.sampled(1, beta) * self.omega_deg,
+                acceleration: self.sampled(2, beta) * self.omega_deg2,
+                jerk: self.sampled(3, beta) * self.omega_deg3,
+            },
+            Phase::Outside => MotionSample { displacement: 0.0, velocity: 0.0, acceleration: 0.0, jerk: 0.0 },
+        }
+    }
+
+    /// Classify a reusable angle grid once for repeated [`evaluate_grid`](Self::evaluate_grid) calls
+    pub fn precompute_grid(&self, angles: &[f64]) -> PrecomputedGrid {
+        PrecomputedGrid {
+            angles: angles.to_vec(),
+            phases: angles.iter().map(|&theta| self.classify(theta)).collect(),
+            durations: (self.params.rise_duration, self.params.dwell_duration, self.params.fall_duration),
+        }
+    }
+
+    /// Evaluate all four channels on a precomputed grid, skipping the phase detection
+    ///
+    /// The grid must come from a law with the same rise/dwell/fall durations; only
+    /// lift, RPM and shape settings may differ.
+    pub fn evaluate_grid(&self, grid: &PrecomputedGrid) -> KinematicArrays {
+        debug_assert_eq!(
+            grid.durations,
+            (self.params.rise_duration, self.params.dwell_duration, self.params.fall_duration),
+            "PrecomputedGrid built for different phase durations"
+        );
+        let n = grid.len();
+        let mut out = KinematicArrays {
+            displacement: Vec::with_capacity(n),
+            velocity: Vec::with_capacity(n),
+            acceleration: Vec::with_capacity(n),
+            jerk: Vec::with_capacity(n),
+        };
+        for &(phase, beta) in &grid.phases {
+            let sample = self.sample_phase(phase, beta);
+            out.displacement.push(sample.displacement);
+            out.velocity.push(sample.velocity);
+            out.acceleration.push(sample.acceleration);
+            out.jerk.push(sample.jerk);
+        }
+        out
+    }
+
     /// Calculate displacement for multiple angles in parallel
     ///
     /// This method leverages rayon for parallel computation when processing
     /// large arrays of angles, which is common in FEA simulations.
     pub fn displacement_parallel(&self, theta_values: &[f64]) -> Vec<f64> {
-        theta_values
-            .par_iter()
+        maybe_par_iter!(theta_values)
             .map(|&theta| self.displacement(theta))
             .collect()
     }
 
     /// Calculate velocity for multiple angles in parallel
     pub fn velocity_parallel(&self, theta_values: &[f64]) -> Vec<f64> {
-        theta_values
-            .par_iter()
+        maybe_par_iter!(theta_values)
             .map(|&theta| self.velocity(theta))
             .collect()
     }
 
     /// Calculate acceleration for multiple angles in parallel
     pub fn acceleration_parallel(&self, theta_values: &[f64]) -> Vec<f64> {
-        theta_values
-            .par_iter()
+        maybe_par_iter!(theta_values)
             .map(|&theta| self.acceleration(theta))
             .collect()
     }
 
     /// Calculate jerk for multiple angles in parallel
     pub fn jerk_parallel(&self, theta_values: &[f64]) -> Vec<f64> {
-        theta_values
-            .par_iter()
+        maybe_par_iter!(theta_values)
             .map(|&theta| self.jerk(theta))
             .collect()
     }
 
+    /// [`analyze_kinematics`](Self::analyze_kinematics) with a sanity cap on the point count
+    ///
+    /// The analysis holds five `f64` arrays of `num_points` each, so host-supplied counts
+    /// are checked first: more than `max_points` (see [`DEFAULT_MAX_ANALYSIS_POINTS`]) or
+    /// fewer than 2 points is a `ParameterValidation` error instead of an allocation failure.
+    pub fn analyze_kinematics_checked(&self, num_points: usize, max_points: usize) -> FEAResult<KinematicAnalysis> {
+        if num_points > max_points {
+            return Err(FEAError::ParameterValidation(format!(
+                "num_points {} exceeds the analysis limit of {} (about {} MB of arrays)",
+                num_points,
+                max_points,
+                num_points.saturating_mul(5 * std::mem::size_of::<f64>()) / 1_000_000
+            )));
+        }
+        if num_points < 2 {
+            return Err(FEAError::ParameterValidation(format!("num_points must be at least 2, got {}", num_points)));
+        }
+        Ok(self.analyze_kinematics(num_points))
+    }
+
     /// Perform comprehensive kinematic analysis
     ///
     /// This method provides the same analysis as the Python version but with
@@ -333,13 +1246,15 @@ impl MotionLaw {
         let acceleration = self.acceleration_parallel(&theta);
         let jerk = self.jerk_parallel(&theta);
 
-        // Calculate statistics
-        let max_velocity = velocity.iter().map(|v| v.abs()).fold(0.0, f64::max);
-        let max_acceleration = acceleration.iter().map(|a| a.abs()).fold(0.0, f64::max);
-        let max_jerk = jerk.iter().map(|j| j.abs()).fold(0.0, f64::max);
+        // Calculate statistics (deterministic parallel reductions)
+        let max_velocity = reduce_max_abs(&velocity);
+        let max_acceleration = reduce_max_abs(&acceleration);
+        let max_jerk = reduce_max_abs(&jerk);
 
-        let rms_acceleration = (acceleration.iter().map(|a| a * a).sum::<f64>() / acceleration.len() as f64).sqrt();
-        let rms_jerk = (jerk.iter().map(|j| j * j).sum::<f64>() / jerk.len() as f64).sqrt();
+        let rms_acceleration = (reduce_sum(&acceleration, |a| a * a) / acceleration.len() as f64).sqrt();
+        let rms_jerk = (reduce_sum(&jerk, |j| j * j) / jerk.len() as f64).sqrt();
+        let rms_velocity = (reduce_sum(&velocity, |v| v * v) / velocity.len() as f64).sqrt();
+        let mean_displacement = reduce_sum(&displacement, |x| x) / displacement.len() as f64;
 
         // Check constraint violations
         let velocity_violation = max_velocity > self.params.velocity_limit;
@@ -357,44 +1272,362 @@ impl MotionLaw {
             max_jerk,
             rms_acceleration,
             rms_jerk,
+            rms_velocity,
+            mean_displacement,
             velocity_violation,
             acceleration_violation,
             jerk_violation,
         }
     }
 
+    /// Report how close the analysed peaks come to the configured limits, in percent
+    ///
+    /// Values above 100 correspond to the violation flags in [`KinematicAnalysis`].
+    pub fn limit_utilization(&self, analysis: &KinematicAnalysis) -> LimitUtilization {
+        LimitUtilization {
+            velocity_pct: analysis.max_velocity / self.params.velocity_limit * 100.0,
+            acceleration_pct: analysis.max_acceleration / self.params.acceleration_limit * 100.0,
+            jerk_pct: analysis.max_jerk / self.params.jerk_limit * 100.0,
+        }
+    }
+
+    /// Retime the profile to the fastest speed that respects all kinematic limits
+    ///
+    /// The lift-vs-angle geometry is kept and only the RPM is scaled. Peak velocity
+    /// scales with RPM, peak acceleration with RPM² and peak jerk with RPM³, so the
+    /// largest feasible scale is `min(v_lim/v_max, sqrt(a_lim/a_max), cbrt(j_lim/j_max))`.
+    /// Peaks are taken from [`analyze_kinematics`](Self::analyze_kinematics) with
+    /// `RETIME_SAMPLES` points, so the binding limit is met exactly on that grid.
+    pub fn retime_to_limits(&self) -> FEAResult<MotionLaw> {
+        let analysis = self.analyze_kinematics(RETIME_SAMPLES);
+
+        let scale = [
+            (analysis.max_velocity, self.params.velocity_limit, 1.0),
+            (analysis.max_acceleration, self.params.acceleration_limit, 2.0),
+            (analysis.max_jerk, self.params.jerk_limit, 3.0),
+        ]
+        .iter()
+        .filter(|(peak, _, _)| *peak > 0.0)
+        .map(|&(peak, limit, order)| (limit / peak).powf(1.0 / order))
+        .fold(f64::INFINITY, f64::min);
+
+        if !scale.is_finite() || scale <= 0.0 {
+            return Err(FEAError::Calculation(
+                "Cannot retime a motion law without any non-zero kinematic peak".to_string()
+            ));
+        }
+
+        let mut retimed = MotionLaw::new(MotionParameters {
+            rpm: self.params.rpm * scale,
+            ..self.params.clone()
+        })?;
+        retimed.samples = self.samples.clone();
+        Ok(retimed)
+    }
+
     /// Calculate boundary conditions for FEA at specific time steps
     ///
     /// This is a critical method for FEA integration that provides displacement,
     /// velocity, and acceleration boundary conditions at specified time points.
     pub fn boundary_conditions(&self, time_steps: &[f64]) -> Vec<(f64, f64, f64)> {
-        time_steps
-            .par_iter()
+        maybe_par_iter!(time_steps)
             .map(|&t| {
                 // Convert time to cam angle
-                let theta = (t * self.omega * 180.0 / PI) % 360.0;
+                let theta = (t * self.omega_deg) % 360.0;
+
+                let sample = self.evaluate(theta);
+                (sample.displacement, sample.velocity, sample.acceleration)
+            })
+            .collect()
+    }
+
+    /// Calculate boundary conditions for a fixed number of uniform time steps
+    ///
+    /// Evaluates `t0, t0 + dt, ..., t0 + (N - 1) * dt` into a stack-allocated array,
+    /// avoiding heap allocation in real-time control loops.
+    pub fn boundary_conditions_array<const N: usize>(&self, t0: f64, dt: f64) -> [(f64, f64, f64); N] {
+        std::array::from_fn(|i| self.boundary_condition_at_time(t0 + i as f64 * dt))
+    }
+
+    /// Find cam angle ranges where the follower separates from the cam (jump)
+    ///
+    /// Separation occurs when the inertia force required to decelerate the follower,
+    /// `-follower_mass * acceleration`, exceeds the return spring force
+    /// `preload + spring_rate * displacement`. Mass is in kg, spring rate in N/mm and
+    /// preload in N; the acceleration is converted from mm/s² to m/s².
+    ///
+    /// Returns `(start_deg, end_deg)` ranges sampled on a 0.1° grid over the cam duration.
+    pub fn separation_angles(&self, follower_mass: f64, spring_rate: f64, preload: f64) -> Vec<(f64, f64)> {
+        let step_deg = 0.1;
+        let num_steps = (self.total_duration / step_deg).ceil() as usize;
+
+        let mut ranges = Vec::new();
+        let mut in_separation = false;
+        let mut start_deg = 0.0;
+        let mut last_deg = 0.0;
+
+        for i in 0..=num_steps {
+            let theta = (i as f64 * step_deg).min(self.total_duration);
+            let inertia_force = -follower_mass * self.acceleration(theta) * 1e-3;
+            let spring_force = preload + spring_rate * self.displacement(theta);
+
+            if inertia_force > spring_force {
+                if !in_separation {
+                    in_separation = true;
+                    start_deg = theta;
+                }
+            } else if in_separation {
+                ranges.push((start_deg, last_deg));
+                in_separation = false;
+            }
+            last_deg = theta;
+        }
+        if in_separation {
+            ranges.push((start_deg, last_deg));
+        }
+
+        ranges
+    }
+
+    /// Radius of curvature of the cam pitch curve at `theta` (deg), in mm
+    ///
+    /// The pitch curve is r(θ) = base circle radius + displacement, and
+    /// ρ = (r² + r'²)^{3/2} / |r² + 2r'² − r·r''| with derivatives per radian.
+    /// Returns `f64::INFINITY` where the curve is locally straight.
+    pub fn radius_of_curvature(&self, theta: f64) -> f64 {
+        let deg_per_rad = 180.0 / PI;
+        let r = self.params.base_circle_radius + self.displacement(theta);
+        let dr = self.velocity(theta) / self.omega_deg * deg_per_rad;
+        let ddr = self.displacement_dd_theta(theta) * deg_per_rad * deg_per_rad;
+        let denom = (r * r + 2.0 * dr * dr - r * ddr).abs();
+        if denom == 0.0 {
+            return f64::INFINITY;
+        }
+        (r * r + dr * dr).powf(1.5) / denom
+    }
 
-                let displacement = self.displacement(theta);
-                let velocity = self.velocity(theta);
-                let acceleration = self.acceleration(theta);
+    /// Smallest pitch-curve radius of curvature over a revolution and the angle (deg) where it occurs
+    ///
+    /// Scans `num_points` evenly spaced angles in [0, 360) in parallel; the earliest
+    /// angle wins ties.
+    pub fn global_min_radius_of_curvature(&self, num_points: usize) -> (f64, f64) {
+        let step = 360.0 / num_points.max(1) as f64;
+        maybe_into_par_iter!(0..num_points.max(1))
+            .map(|i| {
+                let theta = i as f64 * step;
+                (self.radius_of_curvature(theta), theta)
+            })
+            .min_by(|a, b| a.0.total_cmp(&b.0).then(a.1.total_cmp(&b.1)))
+            .unwrap_or((f64::INFINITY, 0.0))
+    }
 
-                (displacement, velocity, acceleration)
+    /// Area (mm²) enclosed by the pitch curve r(θ) = base circle radius + displacement
+    ///
+    /// Evaluates the polar integral ½∮r² dθ with the rectangle rule over `num_points`
+    /// evenly spaced angles in [0, 360), which converges quickly for a periodic curve.
+    pub fn enclosed_area(&self, num_points: usize) -> f64 {
+        let n = num_points.max(1);
+        let step = 360.0 / n as f64;
+        let sum_r2: f64 = (0..n)
+            .map(|i| {
+                let r = self.params.base_circle_radius + self.displacement(i as f64 * step);
+                r * r
             })
+            .sum();
+        0.5 * sum_r2 * (2.0 * PI / n as f64)
+    }
+
+    /// Centroid (x, y) in mm of the area enclosed by the pitch curve, about the cam axis
+    ///
+    /// Uses x̄ = ⅓∮r³ cos θ dθ / A and ȳ = ⅓∮r³ sin θ dθ / A on the same `num_points`
+    /// grid as [`enclosed_area`](Self::enclosed_area); θ = 0 lies along +x.
+    pub fn centroid(&self, num_points: usize) -> (f64, f64) {
+        let n = num_points.max(1);
+        let step = 360.0 / n as f64;
+        let (mx, my) = (0..n).fold((0.0, 0.0), |(mx, my), i| {
+            let theta = i as f64 * step;
+            let r = self.params.base_circle_radius + self.displacement(theta);
+            let (sin, cos) = theta.to_radians().sin_cos();
+            (mx + r * r * r * cos, my + r * r * r * sin)
+        });
+        let d_theta = 2.0 * PI / n as f64;
+        let area = self.enclosed_area(n);
+        (mx * d_theta / (3.0 * area), my * d_theta / (3.0 * area))
+    }
+
+    /// Pitch-curve point list (x, y) in mm for CAM export, simplified to `tolerance_mm`
+    ///
+    /// Samples the pitch curve r(θ) = base circle radius + displacement at
+    /// [`PROFILE_POLYLINE_SAMPLES`] evenly spaced angles (θ = 0 along +x) and applies
+    /// Douglas-Peucker: every sample lies within `tolerance_mm` of the returned polyline.
+    /// The polyline is closed, so the last point repeats the first.
+    pub fn profile_polyline(&self, tolerance_mm: f64) -> Vec<(f64, f64)> {
+        let n = PROFILE_POLYLINE_SAMPLES;
+        let step = 360.0 / n as f64;
+        let pts: Vec<(f64, f64)> = (0..=n)
+            .map(|i| {
+                let theta = (i % n) as f64 * step;
+                let r = self.params.base_circle_radius + self.displacement(theta);
+                let (sin, cos) = theta.to_radians().sin_cos();
+                (r * cos, r * sin)
+            })
+            .collect();
+
+        let mut keep = vec![false; pts.len()];
+        keep[0] = true;
+        keep[n] = true;
+        // The closed curve starts and ends at the same point, so split it at the far side first
+        let mut spans = vec![(0, n / 2), (n / 2, n)];
+        keep[n / 2] = true;
+        while let Some((a, b)) = spans.pop() {
+            let (farthest, dist) = (a + 1..b)
+                .map(|i| (i, point_segment_distance(pts[i], pts[a], pts[b])))
+                .fold((a, 0.0), |best, cur| if cur.1 > best.1 { cur } else { best });
+            if dist > tolerance_mm {
+                keep[farthest] = true;
+                spans.push((a, farthest));
+                spans.push((farthest, b));
+            }
+        }
+        pts.into_iter().zip(keep).filter_map(|(p, k)| k.then_some(p)).collect()
+    }
+
+    /// Drive torque (N·m) needed against a constant follower force `force` (N)
+    ///
+    /// τ(θ) = F · dx/dθ with dx/dθ = velocity / ω in mm per radian, at `num_points`
+    /// evenly spaced angles in [0, 360). Positive torque resists the drive.
+    pub fn torque_demand(&self, force: f64, num_points: usize) -> Vec<f64> {
+        let step = 360.0 / num_points.max(1) as f64;
+        (0..num_points.max(1))
+            .map(|i| force * self.velocity(i as f64 * step) / self.omega * 1e-3)
             .collect()
     }
 
+    /// Largest-magnitude torque of [`torque_demand`](Self::torque_demand) (signed, N·m)
+    /// and the angle (deg) where it occurs; the earliest angle wins ties.
+    pub fn peak_torque(&self, force: f64, num_points: usize) -> (f64, f64) {
+        let step = 360.0 / num_points.max(1) as f64;
+        self.torque_demand(force, num_points)
+            .into_iter()
+            .enumerate()
+            .fold((0.0, 0.0), |best: (f64, f64), (i, tau)| {
+                if tau.abs() > best.0.abs() { (tau, i as f64 * step) } else { best }
+            })
+    }
+
+    /// Effective radius of the cam profile envelope at `theta` (deg), in mm
+    ///
+    /// The envelope x = r·cos θ − r'·sin θ, y = r·sin θ + r'·cos θ of the pitch curve
+    /// r(θ) = base circle radius + displacement has radius of curvature r + r'' (derivatives
+    /// per radian). Where it turns negative the envelope loops back over itself.
+    pub fn effective_radius(&self, theta: f64) -> f64 {
+        let deg_per_rad = 180.0 / PI;
+        self.params.base_circle_radius + self.displacement(theta) + self.displacement_dd_theta(theta) * deg_per_rad * deg_per_rad
+    }
+
+    /// First angle (deg) where the cam profile self-intersects, if any
+    ///
+    /// Scans `num_points` evenly spaced angles in [0, 360) for a negative
+    /// [`effective_radius`](Self::effective_radius), which a lobe pinches into when the
+    /// displacement variation outgrows the base circle.
+    pub fn self_intersection_angle(&self, num_points: usize) -> Option<f64> {
+        let step = 360.0 / num_points.max(1) as f64;
+        (0..num_points.max(1))
+            .map(|i| i as f64 * step)
+            .find(|&theta| self.effective_radius(theta) < 0.0)
+    }
+
+    /// Whether the cam profile self-intersects anywhere on a `num_points` scan
+    pub fn has_self_intersection(&self, num_points: usize) -> bool {
+        self.self_intersection_angle(num_points).is_some()
+    }
+
+    /// Angles (deg, ascending in [0, 360)) where the acceleration changes sign
+    ///
+    /// Scans `num_points` evenly spaced angles around the full revolution, treating
+    /// |a| below 1e-9 of the peak as zero, and refines each crossing by bisection.
+    /// A zero-acceleration span longer than one sample (a dwell) is reported by its
+    /// two boundaries instead of every point in it. The scan wraps, so the base-circle
+    /// span after the fall ends where the next rise begins.
+    pub fn acceleration_zero_crossings(&self, num_points: usize) -> Vec<f64> {
+        let n = num_points.max(2);
+        let step = 360.0 / n as f64;
+        let accel: Vec<f64> = (0..n).map(|i| self.acceleration(i as f64 * step)).collect();
+        let tol = 1e-9 * accel.iter().map(|a| a.abs()).fold(0.0, f64::max);
+        let sign = |a: f64| if a.abs() <= tol { 0 } else if a > 0.0 { 1 } else { -1 };
+        let s: Vec<i32> = accel.iter().map(|&a| sign(a)).collect();
+        let Some(k0) = s.iter().position(|&v| v != 0) else {
+            return Vec::new();
+        };
+
+        // Bisect on a predicate that differs at the two ends
+        let refine = |mut lo: f64, mut hi: f64, pred: &dyn Fn(f64) -> bool| {
+            let p_lo = pred(lo);
+            for _ in 0..60 {
+                let mid = 0.5 * (lo + hi);
+                if pred(mid) == p_lo { lo = mid } else { hi = mid }
+            }
+            0.5 * (lo + hi)
+        };
+        let positive = |theta: f64| self.acceleration(theta) > 0.0;
+        let zero = |theta: f64| self.acceleration(theta).abs() <= tol;
+        let theta_at = |k: usize| k as f64 * step;
+
+        // Walk one full turn starting from a non-zero sample, in unwrapped sample indices
+        let mut crossings = Vec::new();
+        let mut last = k0;
+        let mut k = k0 + 1;
+        while k <= k0 + n {
+            if s[k % n] == 0 {
+                let run_start = k;
+                while s[k % n] == 0 {
+                    k += 1;
+                }
+                if k - run_start == 1 {
+                    if s[last % n] != s[k % n] {
+                        crossings.push(refine(theta_at(last), theta_at(k), &positive));
+                    }
+                } else {
+                    crossings.push(refine(theta_at(run_start - 1), theta_at(run_start), &zero));
+                    crossings.push(refine(theta_at(k - 1), theta_at(k), &zero));
+                }
+            } else if s[k % n] != s[last % n] {
+                crossings.push(refine(theta_at(last), theta_at(k), &positive));
+            }
+            last = k;
+            k += 1;
+        }
+
+        let mut crossings: Vec<f64> = crossings.into_iter().map(|theta| theta.rem_euclid(360.0)).collect();
+        crossings.sort_by(f64::total_cmp);
+        crossings
+    }
+
     /// Optimized method for real-time boundary condition calculation
     ///
     /// This method is designed for maximum performance during FEA simulation
     /// where boundary conditions need to be calculated at every time step.
     #[inline]
     pub fn boundary_condition_at_time(&self, time: f64) -> (f64, f64, f64) {
-        let theta = (time * self.omega * 180.0 / PI) % 360.0;
-        (
-            self.displacement(theta),
-            self.velocity(theta),
-            self.acceleration(theta),
-        )
+        let theta = (time * self.omega_deg) % 360.0;
+        let sample = self.evaluate(theta);
+        (sample.displacement, sample.velocity, sample.acceleration)
+    }
+
+    /// Continuous time driver over several revolutions
+    ///
+    /// Yields `(time, displacement, velocity, acceleration)` at
+    /// `round(revolutions * steps_per_rev)` evenly spaced times starting at 0, with
+    /// `steps_per_rev` steps per revolution at the configured RPM. Time keeps
+    /// accumulating across revolutions while the cam angle wraps.
+    pub fn simulate(&self, revolutions: f64, steps_per_rev: usize) -> impl Iterator<Item = (f64, f64, f64, f64)> + '_ {
+        let count = (revolutions.max(0.0) * steps_per_rev as f64).round() as usize;
+        let dt = 60.0 / self.params.rpm / steps_per_rev.max(1) as f64;
+        (0..count).map(move |i| {
+            let time = i as f64 * dt;
+            let (displacement, velocity, acceleration) = self.boundary_condition_at_time(time);
+            (time, displacement, velocity, acceleration)
+        })
     }
 }
 
@@ -495,6 +1728,107 @@ mod tests {
         assert!(analysis.rms_acceleration > 0.0);
     }
 
+    #[test]
+    fn test_content_hash() {
+        let a = MotionLaw::new(MotionParameters::default()).unwrap().analyze_kinematics(500);
+        let b = MotionLaw::new(MotionParameters::default()).unwrap().analyze_kinematics(500);
+        assert_eq!(a.content_hash(), b.content_hash());
+        assert_eq!(a.content_hash().len(), 16);
+
+        // Last-bit noise is absorbed by the rounding
+        let mut noisy = a.clone();
+        noisy.displacement[100] *= 1.0 + f64::EPSILON;
+        assert_eq!(noisy.content_hash(), a.content_hash());
+
+        let changed = MotionLaw::new(MotionParameters { max_lift: 10.5, ..MotionParameters::default() })
+            .unwrap()
+            .analyze_kinematics(500);
+        assert_ne!(changed.content_hash(), a.content_hash());
+    }
+
+    #[test]
+    fn test_kinematic_analysis_display() {
+        let analysis = MotionLaw::new(MotionParameters::default()).unwrap().analyze_kinematics(500);
+        let line = analysis.to_string();
+        assert!(line.contains("max_velocity="));
+        assert!(line.contains(&format!("velocity_violation={}", analysis.velocity_violation)));
+        assert!(line.contains(&format!("acceleration_violation={}", analysis.acceleration_violation)));
+        assert!(line.contains(&format!("jerk_violation={}", analysis.jerk_violation)));
+        assert!(!line.contains('\n'));
+    }
+
+    #[test]
+    fn test_limit_utilization() {
+        let motion = MotionLaw::new(MotionParameters::default()).unwrap();
+        let peak_velocity = motion.analyze_kinematics(1000).max_velocity;
+
+        let at_limit = MotionLaw::new(MotionParameters {
+            velocity_limit: peak_velocity,
+            ..MotionParameters::default()
+        })
+        .unwrap();
+        let analysis = at_limit.analyze_kinematics(1000);
+        let utilization = at_limit.limit_utilization(&analysis);
+
+        assert_relative_eq!(utilization.velocity_pct, 100.0, epsilon = 1e-9);
+        assert_relative_eq!(
+            utilization.jerk_pct,
+            analysis.max_jerk / at_limit.parameters().jerk_limit * 100.0,
+            epsilon = 1e-9
+        );
+        assert_eq!(utilization.acceleration_pct > 100.0, analysis.acceleration_violation);
+    }
+
+    #[test]
+    fn test_retime_to_limits() {
+        let params = MotionParameters::default();
+        let motion = MotionLaw::new(params.clone()).unwrap();
+
+        let retimed = motion.retime_to_limits().unwrap();
+        let analysis = retimed.analyze_kinematics(RETIME_SAMPLES);
+
+        let utilization = [
+            analysis.max_velocity / params.velocity_limit,
+            analysis.max_acceleration / params.acceleration_limit,
+            analysis.max_jerk / params.jerk_limit,
+        ];
+        for u in utilization {
+            assert!(u <= 1.0 + 1e-9, "limit exceeded: utilization {}", u);
+        }
+        let binding = utilization.iter().filter(|&&u| (u - 1.0).abs() < 1e-9).count();
+        assert_eq!(binding, 1, "utilizations: {:?}", utilization);
+
+        // Only the speed changes; the lift geometry is untouched
+        assert_relative_eq!(retimed.displacement(45.0), motion.displacement(45.0), epsilon = 1e-12);
+    }
+
+    #[test]
+    fn test_snapshot_round_trip() {
+        let params = MotionParameters {
+            modified_sine_variant: ModifiedSineVariant::SplitRegion,
+            ..MotionParameters::default()
+        };
+        let motion = MotionLaw::new(params).unwrap();
+
+        let json = serde_json::to_string(&motion.snapshot()).unwrap();
+        let snapshot: MotionLawSnapshot = serde_json::from_str(&json).unwrap();
+        let restored = MotionLaw::from_snapshot(snapshot).unwrap();
+
+        for i in 0..360 {
+            let theta = i as f64;
+            assert_eq!(restored.displacement(theta), motion.displacement(theta));
+        }
+
+        // Tampered derived constants and invalid parameters are rejected
+        let mut tampered = motion.snapshot();
+        tampered.omega_deg *= 2.0;
+        assert!(MotionLaw::from_snapshot(tampered).is_err());
+
+        let mut invalid = motion.snapshot();
+        invalid.params.max_lift = -1.0;
+        assert!(matches!(MotionLaw::from_snapshot(invalid), Err(FEAError::ParameterValidation(_))));
+    }
+
     #[test]
     fn test_boundary_conditions() {
         let params = MotionParameters::default();
@@ -513,6 +1847,25 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_boundary_conditions_array() {
+        let params = MotionParameters::default();
+        let motion = MotionLaw::new(params).unwrap();
+
+        let (t0, dt) = (0.002, 0.0005);
+        let fixed: [(f64, f64, f64); 8] = motion.boundary_conditions_array::<8>(t0, dt);
+
+        let time_steps: Vec<f64> = (0..8).map(|i| t0 + i as f64 * dt).collect();
+        let dynamic = motion.boundary_conditions(&time_steps);
+
+        assert_eq!(fixed.len(), dynamic.len());
+        for (a, b) in fixed.iter().zip(dynamic.iter()) {
+            assert_relative_eq!(a.0, b.0, epsilon = 1e-12);
+            assert_relative_eq!(a.1, b.1, epsilon = 1e-12);
+            assert_relative_eq!(a.2, b.2, epsilon = 1e-12);
+        }
+    }
+
     #[test]
     fn test_boundary_condition_at_time() {
         let params = MotionParameters::default();
@@ -525,6 +1878,574 @@ mod tests {
         assert!(acc.is_finite());
         assert!(disp >= 0.0);
     }
+
+    #[test]
+    fn test_fall_acceleration_sign() {
+        let params = MotionParameters::default();
+        let motion = MotionLaw::new(params).unwrap();
+
+        // Fall spans 135..225 deg: decelerating first, then accelerating back to rest
+        assert!(motion.acceleration(150.0) < 0.0);
+        assert!(motion.acceleration(210.0) > 0.0);
+
+        // Sign must match d²(displacement)/dθ² from central differences
+        let h = 1e-3;
+        for &theta in &[20.0, 70.0, 150.0, 210.0] {
+            let d2 = (motion.displacement(theta + h) - 2.0 * motion.displacement(theta)
+                + motion.displacement(theta - h)) / (h * h);
+            assert_eq!(d2.signum(), motion.acceleration(theta).signum(), "sign mismatch at {}", theta);
+        }
+
+        // The legacy convention keeps the rise-phase sign in the fall
+        let legacy = MotionLaw::new(MotionParameters {
+            accel_sign_convention: AccelSignConvention::Legacy,
+            ..MotionParameters::default()
+        }).unwrap();
+        assert_relative_eq!(legacy.acceleration(150.0), -motion.acceleration(150.0), epsilon = 1e-12);
+        assert_relative_eq!(legacy.acceleration(70.0), motion.acceleration(70.0), epsilon = 1e-12);
+    }
+
+    #[test]
+    fn test_derivatives_match_finite_differences() {
+        let params = MotionParameters::default();
+        let motion = MotionLaw::new(params).unwrap();
+
+        // Central differences in θ (deg), converted to time via dθ/dt in deg/s
+        let theta_rate = motion.parameters().omega() * 180.0 / PI;
+        let h = 1e-3;
+        let angles = [5.0, 30.0, 45.0, 60.0, 85.0, 140.0, 160.0, 180.0, 200.0, 220.0];
+        for &theta in &angles {
+            let vel_fd = (motion.displacement(theta + h) - motion.displacement(theta - h)) / (2.0 * h) * theta_rate;
+            let acc_fd = (motion.velocity(theta + h) - motion.velocity(theta - h)) / (2.0 * h) * theta_rate;
+            let jerk_fd = (motion.acceleration(theta + h) - motion.acceleration(theta - h)) / (2.0 * h) * theta_rate;
+
+            assert_relative_eq!(motion.velocity(theta), vel_fd, max_relative = 1e-5, epsilon = 1e-6);
+            assert_relative_eq!(motion.acceleration(theta), acc_fd, max_relative = 1e-5, epsilon = 1e-3);
+            assert_relative_eq!(motion.jerk(theta), jerk_fd, max_relative = 1e-5, epsilon = 1.0);
+        }
+    }
+
+    #[test]
+    fn test_split_region_modified_sine() {
+        let standard = MotionLaw::new(MotionParameters::default()).unwrap();
+        let split = MotionLaw::new(MotionParameters {
+            modified_sine_variant: ModifiedSineVariant::SplitRegion,
+            ..MotionParameters::default()
+        }).unwrap();
+
+        // Same end points: full lift at the end of the rise, back to zero after the fall
+        assert_relative_eq!(split.displacement(0.0), 0.0, epsilon = 1e-12);
+        assert_relative_eq!(split.displacement(90.0), 10.0, epsilon = 1e-12);
+        assert_relative_eq!(split.displacement(225.0), 0.0, epsilon = 1e-12);
+
+        // Shape is continuous across the region boundaries
+        let variant = ModifiedSineVariant::SplitRegion;
+        for &b in &[0.125, 0.875] {
+            let h = 1e-9;
+            assert_relative_eq!(variant.shape(b - h), variant.shape(b + h), epsilon = 1e-8);
+            assert_relative_eq!(variant.shape_d1(b - h), variant.shape_d1(b + h), epsilon = 1e-7);
+            assert_relative_eq!(variant.shape_d2(b - h), variant.shape_d2(b + h), epsilon = 1e-6);
+        }
+
+        // Lower peak acceleration for the same lift and rise
+        let a_standard = standard.analyze_kinematics(2000).max_acceleration;
+        let a_split = split.analyze_kinematics(2000).max_acceleration;
+        assert!(a_split < a_standard, "split {} >= standard {}", a_split, a_standard);
+        assert_relative_eq!(a_split / a_standard, 4.0 * PI / (2.0 * (4.0 + PI)), max_relative = 1e-3);
+    }
+
+    #[test]
+    fn test_evaluate_matches_individual_channels() {
+        for variant in [ModifiedSineVariant::Standard, ModifiedSineVariant::SplitRegion] {
+            let params = MotionParameters { modified_sine_variant: variant, ..MotionParameters::default() };
+            let motion = MotionLaw::new(params).unwrap();
+
+            for i in 0..720 {
+                let theta = i as f64 * 0.5;
+                let sample = motion.evaluate(theta);
+                assert_relative_eq!(sample.displacement, motion.displacement(theta), max_relative = 1e-12);
+                assert_relative_eq!(sample.velocity, motion.velocity(theta), max_relative = 1e-12);
+                assert_relative_eq!(sample.acceleration, motion.acceleration(theta), max_relative = 1e-12);
+                assert_relative_eq!(sample.jerk, motion.jerk(theta), max_relative = 1e-12);
+            }
+        }
+    }
+
+    #[test]
+    fn test_cached_omega_constants_unchanged_outputs() {
+        let params = MotionParameters::default();
+        let motion = MotionLaw::new(params.clone()).unwrap();
+
+        // Reference values recompute the angular rate on every call
+        let rate = params.omega() / (PI / 180.0);
+        let shape = params.modified_sine_variant;
+        for &theta in &[10.0, 45.0, 80.0] {
+            let beta = theta / params.rise_duration;
+            let db = 1.0 / params.rise_duration;
+            let vel = params.max_lift * db * shape.shape_d1(beta) * rate;
+            let acc = params.max_lift * db * db * shape.shape_d2(beta) * rate * rate;
+            let jerk = params.max_lift * db * db * db * shape.shape_d3(beta) * rate * rate * rate;
+            assert_relative_eq!(motion.velocity(theta), vel, max_relative = 1e-12);
+            assert_relative_eq!(motion.acceleration(theta), acc, max_relative = 1e-12);
+            assert_relative_eq!(motion.jerk(theta), jerk, max_relative = 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_phase_boundary_classification_consistent() {
+        let motion = MotionLaw::new(MotionParameters::default()).unwrap();
+        let rise = motion.parameters().rise_duration;
+
+        for theta in [rise - 1e-12, rise, rise + 1e-12] {
+            assert_eq!(motion.classify(theta).0, Phase::Rise, "theta = {}", theta);
+            let sample = motion.evaluate(theta);
+            assert_eq!(motion.displacement(theta), sample.displacement);
+            assert_eq!(motion.velocity(theta), sample.velocity);
+            assert_eq!(motion.acceleration(theta), sample.acceleration);
+            assert_eq!(motion.jerk(theta), sample.jerk);
+            assert_relative_eq!(sample.displacement, motion.parameters().max_lift, epsilon = 1e-9);
+        }
+
+        // Clearly past the tolerance the angle belongs to the dwell
+        assert_eq!(motion.classify(rise + 1e-6).0, Phase::Dwell);
+        let strict = MotionLaw::new(MotionParameters { phase_boundary_epsilon: 0.0, ..MotionParameters::default() }).unwrap();
+        assert_eq!(strict.classify(rise + 1e-12).0, Phase::Dwell);
+    }
+
+    #[test]
+    fn test_evaluate_grid_matches_per_angle() {
+        let motion = MotionLaw::new(MotionParameters::default()).unwrap();
+        let angles: Vec<f64> = (0..=800).map(|i| i as f64 * 0.45).collect();
+        let grid = motion.precompute_grid(&angles);
+        assert_eq!(grid.len(), angles.len());
+
+        // Same grid reused at a different RPM
+        let fast = MotionLaw::new(MotionParameters { rpm: 7200.0, ..MotionParameters::default() }).unwrap();
+        for law in [&motion, &fast] {
+            let arrays = law.evaluate_grid(&grid);
+            for (i, &theta) in angles.iter().enumerate() {
+                let expected = law.evaluate(theta);
+                assert_eq!(arrays.displacement[i], expected.displacement);
+                assert_eq!(arrays.velocity[i], expected.velocity);
+                assert_eq!(arrays.acceleration[i], expected.acceleration);
+                assert_eq!(arrays.jerk[i], expected.jerk);
+            }
+        }
+    }
+
+    #[test]
+    fn test_separation_angles() {
+        let params = MotionParameters::default();
+        let motion = MotionLaw::new(params).unwrap();
+
+        // Pick a follower mass whose peak inertia force is 100 N
+        let analysis = motion.analyze_kinematics(1000);
+        let min_accel = analysis.acceleration.iter().cloned().fold(f64::INFINITY, f64::min);
+        assert!(min_accel < 0.0);
+        let follower_mass = 100.0 / (-min_accel * 1e-3);
+
+        // A very soft spring cannot hold the follower at peak deceleration
+        let soft = motion.separation_angles(follower_mass, 0.1, 1.0);
+        assert!(!soft.is_empty());
+        let peak_theta = analysis.theta[analysis.acceleration.iter()
+            .position(|&a| a == min_accel)
+            .unwrap()];
+        assert!(soft.iter().any(|&(start, end)| start <= peak_theta && peak_theta <= end));
+
+        // A stiff, preloaded spring keeps the follower in contact
+        let stiff = motion.separation_angles(follower_mass, 100.0, 1000.0);
+        assert!(stiff.is_empty());
+    }
+
+    #[test]
+    fn test_validate_all_reports_every_problem() {
+        let params = MotionParameters {
+            max_lift: -1.0,
+            rpm: 0.0,
+            jerk_limit: -5.0,
+            ..Default::default()
+        };
+        let errors = params.validate_all();
+        assert_eq!(errors.len(), 3, "unexpected errors: {:?}", errors);
+        assert!(errors[0].to_string().contains("Maximum lift"));
+        assert!(errors[1].to_string().contains("RPM"));
+        assert!(errors[2].to_string().contains("Jerk limit"));
+        assert_eq!(params.validate().unwrap_err().to_string(), errors[0].to_string());
+        assert!(MotionParameters::default().validate_all().is_empty());
+    }
+
+    #[test]
+    fn test_displacement_dd_theta_matches_finite_difference() {
+        let params = MotionParameters {
+            accel_sign_convention: AccelSignConvention::Legacy,
+            ..Default::default()
+        };
+        let motion = MotionLaw::new(params).unwrap();
+        let h = 1e-3;
+        for &theta in &[10.0, 33.3, 60.0, 80.0, 150.0, 170.0, 200.0, 220.0] {
+            let fd = (motion.displacement(theta + h) - 2.0 * motion.displacement(theta) + motion.displacement(theta - h)) / (h * h);
+            let analytic = motion.displacement_dd_theta(theta);
+            assert_relative_eq!(analytic, fd, epsilon = 1e-6, max_relative = 1e-4);
+        }
+    }
+
+    #[test]
+    fn test_from_samples_recovers_velocity() {
+        let analytic = MotionLaw::new(MotionParameters::default()).unwrap();
+        let theta: Vec<f64> = (0..=450).map(|i| i as f64 * 0.5).collect();
+        let lift: Vec<f64> = theta.iter().map(|&t| analytic.displacement(t)).collect();
+        let sampled = MotionLaw::from_samples(theta, lift, analytic.parameters().rpm).unwrap();
+
+        let peak = analytic.analyze_kinematics(1001).max_velocity;
+        for &t in &[10.0, 33.5, 60.0, 80.25, 150.0, 170.0, 200.0] {
+            assert_relative_eq!(sampled.displacement(t), analytic.displacement(t), epsilon = 1e-3);
+            assert_relative_eq!(sampled.velocity(t), analytic.velocity(t), epsilon = 1e-3 * peak);
+            assert_relative_eq!(sampled.evaluate(t).velocity, sampled.velocity(t));
+        }
+        assert_eq!(sampled.displacement(300.0), 0.0);
+
+        assert!(MotionLaw::from_samples(vec![0.0, 1.0], vec![0.0, 1.0, 2.0], 3000.0).is_err());
+        assert!(MotionLaw::from_samples(vec![0.0, 2.0, 1.0], vec![0.0, 1.0, 2.0], 3000.0).is_err());
+    }
+
+    #[test]
+    fn test_five_point_stencil_reduces_derivative_error() {
+        // Coarse samples of a smooth lift with a small deterministic ripple
+        let lift_of = |t: f64| 5.0 * (1.0 - (t * PI / 180.0).cos());
+        let dlift_of = |t: f64| 5.0 * (t * PI / 180.0).sin() * PI / 180.0;
+        let theta: Vec<f64> = (0..=120).map(|i| i as f64 * 3.0).collect();
+        let lift: Vec<f64> = theta
+            .iter()
+            .enumerate()
+            .map(|(i, &t)| lift_of(t) + 1e-6 * ((i * 7919) % 13) as f64 / 13.0)
+            .collect();
+
+        let rms_error = |stencil: DiffStencil| {
+            let law = MotionLaw::from_samples_with_stencil(theta.clone(), lift.clone(), 3000.0, stencil).unwrap();
+            let omega_deg = law.omega() * 180.0 / PI;
+            let sum: f64 = theta.iter().map(|&t| (law.velocity(t) / omega_deg - dlift_of(t)).powi(2)).sum();
+            (sum / theta.len() as f64).sqrt()
+        };
+        let three = rms_error(DiffStencil::ThreePoint);
+        let five = rms_error(DiffStencil::FivePoint);
+        assert!(five < three, "five-point RMS {} not below three-point RMS {}", five, three);
+    }
+
+    #[test]
+    fn test_global_min_radius_of_curvature_matches_serial_scan() {
+        let motion = MotionLaw::new(MotionParameters::default()).unwrap();
+        let n = 7200;
+        let (rho_min, theta_min) = motion.global_min_radius_of_curvature(n);
+
+        let mut serial = (f64::INFINITY, 0.0);
+        for i in 0..n {
+            let theta = i as f64 * 360.0 / n as f64;
+            let rho = motion.radius_of_curvature(theta);
+            if rho < serial.0 {
+                serial = (rho, theta);
+            }
+        }
+        assert_eq!((rho_min, theta_min), serial);
+        assert!(rho_min > 0.0 && rho_min.is_finite());
+
+        // On the base circle the pitch curve is a circle of the base radius
+        let base = motion.parameters().base_circle_radius;
+        assert_relative_eq!(motion.radius_of_curvature(300.0), base, epsilon = 1e-9);
+    }
+
+    #[cfg(not(feature = "parallel"))]
+    #[test]
+    fn test_serial_fallback_matches_per_angle_calls() {
+        let motion = MotionLaw::new(MotionParameters::default()).unwrap();
+        let theta: Vec<f64> = (0..1000).map(|i| i as f64 * 0.25).collect();
+        let per_angle = |f: fn(&MotionLaw, f64) -> f64| theta.iter().map(|&t| f(&motion, t)).collect::<Vec<_>>();
+        assert_eq!(motion.displacement_parallel(&theta), per_angle(MotionLaw::displacement));
+        assert_eq!(motion.velocity_parallel(&theta), per_angle(MotionLaw::velocity));
+        assert_eq!(motion.acceleration_parallel(&theta), per_angle(MotionLaw::acceleration));
+        assert_eq!(motion.jerk_parallel(&theta), per_angle(MotionLaw::jerk));
+    }
+
+    #[test]
+    fn test_self_intersection() {
+        let pinched = MotionLaw::new(MotionParameters { base_circle_radius: 2.0, max_lift: 20.0, ..MotionParameters::default() }).unwrap();
+        let theta = pinched.self_intersection_angle(3600).expect("tiny base circle should self-intersect");
+        assert!(pinched.has_self_intersection(3600));
+        assert!(pinched.effective_radius(theta) < 0.0);
+        assert!((0..(theta * 10.0).round() as usize).all(|i| pinched.effective_radius(i as f64 * 0.1) >= 0.0));
+
+        let large = MotionLaw::new(MotionParameters { base_circle_radius: 100.0, max_lift: 20.0, ..MotionParameters::default() }).unwrap();
+        assert!(!large.has_self_intersection(3600));
+        assert_eq!(large.self_intersection_angle(3600), None);
+        // On the base circle the envelope radius is the base radius
+        assert_relative_eq!(large.effective_radius(300.0), 100.0, epsilon = 1e-9);
+    }
+
+    #[test]
+    fn test_new_lenient() {
+        let tight = MotionParameters { velocity_limit: 1.0, jerk_limit: -1.0, ..MotionParameters::default() };
+        assert!(MotionLaw::new(tight.clone()).is_err());
+        let (motion, warnings) = MotionLaw::new_lenient(tight).unwrap();
+        assert!(warnings.iter().any(|w| w.contains("Jerk limit must be positive")), "{:?}", warnings);
+        assert!(warnings.iter().any(|w| w.contains("Peak velocity")), "{:?}", warnings);
+        assert!(motion.displacement(45.0) > 0.0);
+
+        // Within-limit parameters are warning-free
+        let relaxed = MotionParameters { velocity_limit: 1e9, acceleration_limit: 1e12, jerk_limit: 1e15, ..MotionParameters::default() };
+        assert!(MotionLaw::new_lenient(relaxed).unwrap().1.is_empty());
+
+        // Physically impossible parameters are still rejected
+        assert!(MotionLaw::new_lenient(MotionParameters { max_lift: -1.0, ..MotionParameters::default() }).is_err());
+        assert!(MotionLaw::new_lenient(MotionParameters { rise_duration: 0.0, ..MotionParameters::default() }).is_err());
+    }
+
+    #[test]
+    fn test_acceleration_zero_crossings() {
+        let motion = MotionLaw::new(MotionParameters::default()).unwrap();
+        // Mid-rise and mid-fall sign changes, the dwell span 90°–135°, and the
+        // base-circle span from the end of the fall (225°) round to the next rise (0°)
+        let expected = [0.0, 45.0, 90.0, 135.0, 180.0, 225.0];
+        for num_points in [3600, 1000, 997] {
+            let crossings = motion.acceleration_zero_crossings(num_points);
+            assert_eq!(crossings.len(), expected.len(), "{} points: {:?}", num_points, crossings);
+            for (&got, &want) in crossings.iter().zip(&expected) {
+                assert!((got - want).abs() < 1e-6, "{} points: {} vs {}", num_points, got, want);
+            }
+        }
+    }
+
+    #[test]
+    fn test_displacement_fixed() {
+        let motion = MotionLaw::new(MotionParameters::default()).unwrap();
+        let theta: Vec<f64> = (0..720).map(|i| i as f64 * 0.5).collect();
+        let forward: Vec<i64> = theta.iter().map(|&t| motion.displacement_fixed(t, 6)).collect();
+        let mut reverse: Vec<i64> = theta.iter().rev().map(|&t| motion.displacement_fixed(t, 6)).collect();
+        reverse.reverse();
+        assert_eq!(forward, reverse);
+
+        let from_parallel: Vec<i64> = motion
+            .displacement_parallel(&theta)
+            .iter()
+            .map(|d| (d * 1e6).round() as i64)
+            .collect();
+        assert_eq!(forward, from_parallel);
+        assert_eq!(motion.displacement_fixed(100.0, 3), 10_000);
+        assert_eq!(motion.displacement_fixed(300.0, 9), 0);
+    }
+
+    #[test]
+    fn test_peak_torque_at_peak_velocity() {
+        let motion = MotionLaw::new(MotionParameters::default()).unwrap();
+        let force = 500.0;
+        let torque = motion.torque_demand(force, 3600);
+        assert_eq!(torque.len(), 3600);
+        assert_relative_eq!(torque[300], force * motion.velocity(30.0) / motion.omega() * 1e-3, epsilon = 1e-12);
+
+        let (peak, theta) = motion.peak_torque(force, 3600);
+        assert!(peak.abs() > 0.0);
+        let max_velocity = (0..3600).map(|i| motion.velocity(i as f64 * 0.1).abs()).fold(0.0, f64::max);
+        assert_relative_eq!(motion.velocity(theta).abs(), max_velocity, max_relative = 1e-9);
+        assert_relative_eq!(peak.abs(), force * max_velocity / motion.omega() * 1e-3, max_relative = 1e-9);
+    }
+
+    #[test]
+    fn test_simulate_multi_revolution() {
+        let motion = MotionLaw::new(MotionParameters::default()).unwrap();
+        let steps = 360;
+        let samples: Vec<(f64, f64, f64, f64)> = motion.simulate(2.5, steps).collect();
+        assert_eq!(samples.len(), 900);
+
+        let period = 60.0 / motion.parameters().rpm;
+        assert_relative_eq!(samples[steps].0, period, epsilon = 1e-12);
+        assert!(samples.windows(2).all(|w| w[1].0 > w[0].0), "time must keep increasing");
+        for i in 0..samples.len() - steps {
+            let (a, b) = (samples[i], samples[i + steps]);
+            assert_relative_eq!(a.1, b.1, epsilon = 1e-6);
+            assert_relative_eq!(a.2, b.2, epsilon = 1e-6, max_relative = 1e-6);
+            assert_relative_eq!(a.3, b.3, epsilon = 1e-6, max_relative = 1e-6);
+        }
+    }
+
+    #[test]
+    fn test_analyze_kinematics_point_cap() {
+        let motion = MotionLaw::new(MotionParameters::default()).unwrap();
+        match motion.analyze_kinematics_checked(100_000_000, DEFAULT_MAX_ANALYSIS_POINTS) {
+            Err(FEAError::ParameterValidation(msg)) => assert!(msg.contains("exceeds the analysis limit"), "{}", msg),
+            other => panic!("Expected ParameterValidation error, got {:?}", other.map(|a| a.theta.len())),
+        }
+        assert!(motion.analyze_kinematics_checked(1001, 1000).is_err());
+        assert!(motion.analyze_kinematics_checked(1, 1000).is_err());
+        assert_eq!(motion.analyze_kinematics_checked(1000, 1000).unwrap().theta.len(), 1000);
+    }
+
+    #[test]
+    fn test_scaled_parameters_preserve_shape() {
+        let base = MotionParameters::default();
+        let original = MotionLaw::new(base.clone()).unwrap();
+        let scaled = MotionLaw::new(base.scaled(2.5, 1.0)).unwrap();
+        let a = original.analyze_kinematics(721);
+        let b = scaled.analyze_kinematics(721);
+        let max = |v: &[f64]| v.iter().cloned().fold(f64::MIN, f64::max);
+        assert_relative_eq!(max(&b.displacement), 2.5 * max(&a.displacement), max_relative = 1e-12);
+        for (da, db) in a.displacement.iter().zip(&b.displacement) {
+            assert_relative_eq!(da / base.max_lift, db / scaled.parameters().max_lift, epsilon = 1e-12);
+        }
+
+        // Doubling the cycle time halves velocity at the same angle; limit utilization is unchanged
+        let slow_params = base.scaled(1.0, 2.0);
+        assert_eq!(slow_params.rpm, base.rpm / 2.0);
+        let slow = MotionLaw::new(slow_params).unwrap();
+        assert_relative_eq!(slow.velocity(30.0), original.velocity(30.0) / 2.0, max_relative = 1e-12);
+        let (ua, us) = (original.limit_utilization(&a), slow.limit_utilization(&slow.analyze_kinematics(721)));
+        assert_relative_eq!(ua.velocity_pct, us.velocity_pct, max_relative = 1e-12);
+        assert_relative_eq!(ua.acceleration_pct, us.acceleration_pct, max_relative = 1e-12);
+        assert_relative_eq!(ua.jerk_pct, us.jerk_pct, max_relative = 1e-12);
+    }
+
+    #[test]
+    fn test_displacement_stays_within_lift() {
+        for variant in [ModifiedSineVariant::Standard, ModifiedSineVariant::SplitRegion] {
+            for (rise, dwell, fall) in [(90.0, 45.0, 90.0), (120.0, 0.0, 120.0), (37.0, 11.0, 173.0)] {
+                let params = MotionParameters {
+                    modified_sine_variant: variant,
+                    rise_duration: rise,
+                    dwell_duration: dwell,
+                    fall_duration: fall,
+                    cam_duration: rise + dwell + fall,
+                    ..MotionParameters::default()
+                };
+                let max_lift = params.max_lift;
+                let motion = MotionLaw::new(params).unwrap();
+                for i in 0..=36_000 {
+                    let theta = i as f64 * 0.01;
+                    let s = motion.displacement(theta);
+                    assert!((0.0..=max_lift + 1e-9).contains(&s), "{:?} s({}) = {}", variant, theta, s);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_enclosed_area_of_near_circular_cam() {
+        let motion = MotionLaw::new(MotionParameters { max_lift: 1e-9, ..MotionParameters::default() }).unwrap();
+        let r = motion.parameters().base_circle_radius;
+        assert_relative_eq!(motion.enclosed_area(3600), PI * r * r, max_relative = 1e-9);
+        let (cx, cy) = motion.centroid(3600);
+        assert!(cx.abs() < 1e-6 && cy.abs() < 1e-6, "centroid ({}, {})", cx, cy);
+
+        // The lobe adds area and pulls the centroid towards the dwell (90°..135° on defaults)
+        let lobed = MotionLaw::new(MotionParameters::default()).unwrap();
+        assert!(lobed.enclosed_area(3600) > PI * r * r);
+        let (lx, ly) = lobed.centroid(3600);
+        assert!(ly > 0.0 && ly > lx.abs() * 0.5, "centroid ({}, {})", lx, ly);
+    }
+
+    #[test]
+    fn test_downsample_preserves_peaks() {
+        let motion = MotionLaw::new(MotionParameters::default()).unwrap();
+        let full = motion.analyze_kinematics(200_001);
+        let preview = full.downsample(1000);
+        assert!(preview.theta.len() <= 1000 && preview.theta.len() > 100);
+        assert!(preview.theta.windows(2).all(|w| w[0] < w[1]));
+
+        let peak = |v: &[f64]| v.iter().map(|x| x.abs()).fold(0.0, f64::max);
+        assert_eq!(peak(&preview.velocity), full.max_velocity);
+        assert_eq!(peak(&preview.acceleration), full.max_acceleration);
+        assert_eq!(peak(&preview.jerk), full.max_jerk);
+        assert_eq!(preview.max_velocity, full.max_velocity);
+
+        assert_eq!(full.downsample(full.theta.len()).theta.len(), full.theta.len());
+    }
+
+    #[test]
+    fn test_motion_parameters_from_env_with_defaults() {
+        // A prefix of its own keeps this test independent of others touching the environment
+        std::env::set_var("FEA_ENV_TEST_MAX_LIFT", "12.5");
+        std::env::set_var("FEA_ENV_TEST_RPM", " 1500 ");
+        std::env::set_var("FEA_ENV_TEST_MODIFIED_SINE_VARIANT", "Split_Region");
+        let params = MotionParameters::from_env_with_defaults("FEA_ENV_TEST").unwrap();
+        assert_eq!(params.max_lift, 12.5);
+        assert_eq!(params.rpm, 1500.0);
+        assert_eq!(params.modified_sine_variant, ModifiedSineVariant::SplitRegion);
+        let def = MotionParameters::default();
+        assert_eq!(params.base_circle_radius, def.base_circle_radius);
+        assert_eq!(params.rise_duration, def.rise_duration);
+
+        std::env::set_var("FEA_ENV_BAD_RPM", "fast");
+        let err = MotionParameters::from_env_with_defaults("FEA_ENV_BAD").unwrap_err();
+        assert!(err.to_string().contains("FEA_ENV_BAD_RPM"), "{}", err);
+        std::env::set_var("FEA_ENV_BAD_RPM", "-1");
+        assert!(matches!(MotionParameters::from_env_with_defaults("FEA_ENV_BAD"), Err(FEAError::ParameterValidation(_))));
+    }
+
+    #[test]
+    fn test_merge_partial_parameters() {
+        let base = MotionParameters { max_lift: 12.0, modified_sine_variant: ModifiedSineVariant::SplitRegion, ..MotionParameters::default() };
+        let merged = base.merge(&PartialMotionParameters { rpm: Some(1200.0), ..PartialMotionParameters::default() });
+        assert_eq!(merged, MotionParameters { rpm: 1200.0, ..base.clone() });
+        assert_eq!(base.merge(&PartialMotionParameters::default()), base);
+
+        let overrides: PartialMotionParameters = serde_json::from_str(r#"{"max_lift": 8.0, "accel_sign_convention": "Legacy"}"#).unwrap();
+        let merged = base.merge(&overrides);
+        assert_eq!(merged.max_lift, 8.0);
+        assert_eq!(merged.accel_sign_convention, AccelSignConvention::Legacy);
+        assert_eq!(merged.rpm, base.rpm);
+    }
+
+    #[test]
+    fn test_deterministic_reductions_match_sequential() {
+        let motion = MotionLaw::new(MotionParameters::default()).unwrap();
+        let analysis = motion.analyze_kinematics(1_000_001);
+        let v = &analysis.velocity;
+        assert!(v.len() > 8 * REDUCTION_CHUNK);
+
+        // Sequential references with the same fixed chunking
+        let seq_max = v.iter().map(|x| x.abs()).fold(0.0, f64::max);
+        let seq_sum: f64 = v.chunks(REDUCTION_CHUNK).map(|c| c.iter().map(|x| x * x).sum::<f64>()).sum();
+        assert_eq!(reduce_max_abs(v).to_bits(), seq_max.to_bits());
+        assert_eq!(reduce_sum(v, |x| x * x).to_bits(), seq_sum.to_bits());
+        assert_eq!(analysis.max_velocity.to_bits(), seq_max.to_bits());
+        assert_eq!(analysis.rms_velocity.to_bits(), (seq_sum / v.len() as f64).sqrt().to_bits());
+
+        let again = motion.analyze_kinematics(1_000_001);
+        assert_eq!(again.rms_jerk.to_bits(), analysis.rms_jerk.to_bits());
+        assert_eq!(again.mean_displacement.to_bits(), analysis.mean_displacement.to_bits());
+    }
+
+    #[test]
+    fn test_profile_polyline_tolerance() {
+        let motion = MotionLaw::new(MotionParameters::default()).unwrap();
+        let coarse = motion.profile_polyline(0.1);
+        let fine = motion.profile_polyline(0.001);
+        assert!(fine.len() > coarse.len(), "{} vs {}", fine.len(), coarse.len());
+        assert!(coarse.len() < PROFILE_POLYLINE_SAMPLES / 10);
+        assert_eq!(coarse.first(), coarse.last());
+
+        // Every pitch-curve sample lies within tolerance of the polyline
+        let step = 360.0 / PROFILE_POLYLINE_SAMPLES as f64;
+        for (tol, line) in [(0.1, &coarse), (0.001, &fine)] {
+            for i in 0..PROFILE_POLYLINE_SAMPLES {
+                let theta = i as f64 * step;
+                let r = motion.parameters().base_circle_radius + motion.displacement(theta);
+                let p = (r * theta.to_radians().cos(), r * theta.to_radians().sin());
+                let d = line.windows(2).map(|w| point_segment_distance(p, w[0], w[1])).fold(f64::INFINITY, f64::min);
+                assert!(d <= tol + 1e-12, "sample {} deviates {} > {}", theta, d, tol);
+            }
+        }
+    }
+
+    #[test]
+    fn test_rms_velocity_and_mean_displacement() {
+        let motion = MotionLaw::new(MotionParameters::default()).unwrap();
+        let analysis = motion.analyze_kinematics(1000);
+
+        assert!(analysis.rms_velocity > 0.0 && analysis.rms_velocity <= analysis.max_velocity);
+        let n = analysis.velocity.len() as f64;
+        let rms = (analysis.velocity.iter().map(|v| v * v).sum::<f64>() / n).sqrt();
+        assert_relative_eq!(analysis.rms_velocity, rms, epsilon = 1e-9 * rms);
+        assert!(analysis.mean_displacement > 0.0 && analysis.mean_displacement < motion.parameters().max_lift);
+        let mean = analysis.displacement.iter().sum::<f64>() / n;
+        assert_relative_eq!(analysis.mean_displacement, mean, epsilon = 1e-9 * mean);
+    }
 }
 
 #[cfg(test)]
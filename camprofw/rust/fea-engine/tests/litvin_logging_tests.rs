@@ -0,0 +1,35 @@
+extern crate fea_engine;
+
+use fea_engine::litvin::{build_litvin_tables, LitvinParameters};
+use fea_engine::logging::{self, LogLevel, LogRecord, MemoryTarget};
+use std::sync::Arc;
+
+/// Kept in its own test binary: the global logger would otherwise collect records
+/// from Litvin builds running in parallel tests.
+#[test]
+fn test_conjugacy_iterations_logged_at_debug() {
+    assert!(!logging::enabled(LogLevel::Debug), "no logger installed yet");
+    let memory = Arc::new(MemoryTarget::new(1000));
+    logging::init_logger(LogLevel::Debug);
+    logging::add_target(memory.clone());
+    let litvin_records = || -> Vec<LogRecord> {
+        memory.records().into_iter().filter(|r| r.target == "litvin").collect()
+    };
+
+    let params = LitvinParameters { arc_residual_tol_mm: 1e-4, max_iter: 50, ..LitvinParameters::default() };
+    let tables = build_litvin_tables(&params).expect("Failed to build tables");
+    let records = litvin_records();
+    assert_eq!(records.len() as i32, tables.diagnostics.iter_count);
+    for (i, record) in records.iter().enumerate() {
+        assert_eq!(record.level, LogLevel::Debug);
+        assert!(record.message.starts_with(&format!("conjugacy iteration {}:", i + 1)), "{}", record.message);
+        assert!(record.message.contains("scale="), "{}", record.message);
+    }
+
+    // Above Debug the iteration logging is skipped entirely
+    memory.clear();
+    logging::set_min_level(LogLevel::Info);
+    assert!(!logging::enabled(LogLevel::Debug));
+    build_litvin_tables(&params).expect("Failed to build tables");
+    assert!(litvin_records().is_empty());
+}